@@ -0,0 +1,346 @@
+use crate::scalar::ScalarRepr;
+use crate::{ArkGroupWrapper, ArkScalarWrapper};
+use ark_ec::{AffineRepr, CurveGroup, short_weierstrass, twisted_edwards};
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use elliptic_curve::group::cofactor::CofactorGroup;
+use elliptic_curve::group::prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup};
+use elliptic_curve::group::{Curve, Group, GroupEncoding, UncompressedEncoding};
+use elliptic_curve::point::AffineCoordinates;
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+/// A point in affine coordinates, the `group::Curve::AffineRepr` of [`ArkGroupWrapper`].
+//
+// The impls here are generic over the affine type rather than stamped out per curve: naming the
+// affine type of a concrete curve goes through `CurveGroup::Affine`, which coherence cannot tell
+// apart between curves.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ArkAffineWrapper<A>(pub(crate) A);
+
+impl<A: AffineRepr> ArkAffineWrapper<A> {
+    pub fn new(point: A) -> Self {
+        Self(point)
+    }
+
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+/// An affine coordinate over a prime base field as big-endian bytes of the field size, the SEC1
+/// field element encoding that RustCrypto curves return from `AffineCoordinates::x`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CoordinateRepr<F: ArkPrimeField> {
+    // The big-endian bytes fill the whole limb buffer, right-aligned
+    bytes: ScalarRepr<F>,
+}
+
+impl<F: ArkPrimeField> CoordinateRepr<F> {
+    fn new(element: F) -> Self {
+        let mut bytes = ScalarRepr::<F>::default();
+        let buffer = bytes.as_mut();
+        let last = buffer.len() - 1;
+        for (i, limb) in element.into_bigint().as_ref().iter().enumerate() {
+            for (j, byte) in limb.to_le_bytes().into_iter().enumerate() {
+                buffer[last - (8 * i + j)] = byte;
+            }
+        }
+        Self { bytes }
+    }
+}
+
+impl<F: ArkPrimeField> AsRef<[u8]> for CoordinateRepr<F> {
+    fn as_ref(&self) -> &[u8] {
+        let bytes = self.bytes.as_ref();
+        let size = (F::MODULUS_BIT_SIZE as usize).div_ceil(8);
+        &bytes[bytes.len() - size..]
+    }
+}
+
+// The identity reads as zero coordinates.
+impl<A: AffineRepr> ArkAffineWrapper<A>
+where
+    A::BaseField: ArkPrimeField,
+{
+    /// Returns the affine y-coordinate as a serialized field element.
+    pub fn y(&self) -> CoordinateRepr<A::BaseField> {
+        CoordinateRepr::new(self.0.y().unwrap_or_default())
+    }
+
+    /// Is the affine x-coordinate odd?
+    pub fn x_is_odd(&self) -> Choice {
+        Choice::from(u8::from(
+            self.0.x().unwrap_or_default().into_bigint().is_odd(),
+        ))
+    }
+}
+
+impl<A: AffineRepr> AffineCoordinates for ArkAffineWrapper<A>
+where
+    A::BaseField: ArkPrimeField,
+{
+    type FieldRepr = CoordinateRepr<A::BaseField>;
+
+    fn x(&self) -> Self::FieldRepr {
+        CoordinateRepr::new(self.0.x().unwrap_or_default())
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        Choice::from(u8::from(
+            self.0.y().unwrap_or_default().into_bigint().is_odd(),
+        ))
+    }
+}
+
+impl<P: short_weierstrass::SWCurveConfig> ArkGroupWrapper<short_weierstrass::Projective<P>> {
+    /// Builds a point from its affine coordinates, if they are on the curve and in the
+    /// prime-order subgroup.
+    pub fn from_coordinates(x: P::BaseField, y: P::BaseField) -> CtOption<Self> {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time point checks
+        let point = short_weierstrass::Affine::<P>::new_unchecked(x, y);
+        let is_valid = point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve();
+        CtOption::new(
+            Self::new(point.into_group()),
+            Choice::from(u8::from(is_valid)),
+        )
+    }
+}
+
+impl<P: twisted_edwards::TECurveConfig> ArkGroupWrapper<twisted_edwards::Projective<P>> {
+    /// Builds a point from its affine coordinates, if they are on the curve and in the
+    /// prime-order subgroup.
+    pub fn from_coordinates(x: P::BaseField, y: P::BaseField) -> CtOption<Self> {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time point checks
+        let point = twisted_edwards::Affine::<P>::new_unchecked(x, y);
+        let is_valid = point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve();
+        CtOption::new(
+            Self::new(point.into_group()),
+            Choice::from(u8::from(is_valid)),
+        )
+    }
+}
+
+impl<A: AffineRepr> ConstantTimeEq for ArkAffineWrapper<A> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from(u8::from(self.0 == other.0))
+    }
+}
+
+impl<A: AffineRepr> From<ArkAffineWrapper<A>> for ArkGroupWrapper<A::Group> {
+    fn from(point: ArkAffineWrapper<A>) -> Self {
+        Self::new(point.0.into_group())
+    }
+}
+
+impl<C: CurveGroup> From<ArkGroupWrapper<C>> for ArkAffineWrapper<C::Affine> {
+    fn from(point: ArkGroupWrapper<C>) -> Self {
+        Self::new(point.0.into_affine())
+    }
+}
+
+impl<A: AffineRepr> PrimeCurveAffine for ArkAffineWrapper<A>
+where
+    ArkGroupWrapper<A::Group>: PrimeCurve<Affine = Self, Scalar = ArkScalarWrapper<A::ScalarField>>,
+    ArkScalarWrapper<A::ScalarField>: ff::PrimeField,
+    Self: GroupEncoding,
+{
+    type Scalar = ArkScalarWrapper<A::ScalarField>;
+    type Curve = ArkGroupWrapper<A::Group>;
+
+    fn identity() -> Self {
+        Self::new(A::zero())
+    }
+
+    fn generator() -> Self {
+        Self::new(A::generator())
+    }
+
+    fn is_identity(&self) -> Choice {
+        Choice::from(u8::from(self.0.is_zero()))
+    }
+
+    fn to_curve(&self) -> Self::Curve {
+        ArkGroupWrapper::new(self.0.into_group())
+    }
+}
+
+impl<A: AffineRepr> Neg for ArkAffineWrapper<A> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new((-self.0.into_group()).into_affine())
+    }
+}
+
+impl<A: AffineRepr> Mul<ArkScalarWrapper<A::ScalarField>> for ArkAffineWrapper<A> {
+    type Output = ArkGroupWrapper<A::Group>;
+    fn mul(self, scalar: ArkScalarWrapper<A::ScalarField>) -> Self::Output {
+        ArkGroupWrapper::new(self.0 * scalar.into_inner())
+    }
+}
+
+impl<A: AffineRepr> Mul<&ArkScalarWrapper<A::ScalarField>> for ArkAffineWrapper<A> {
+    type Output = ArkGroupWrapper<A::Group>;
+    fn mul(self, scalar: &ArkScalarWrapper<A::ScalarField>) -> Self::Output {
+        ArkGroupWrapper::new(self.0 * scalar.into_inner())
+    }
+}
+
+// Mixed arithmetic between projective and affine points
+impl<C: CurveGroup> Add<ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn add(self, other: ArkAffineWrapper<C::Affine>) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<C: CurveGroup> Add<&ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn add(self, other: &ArkAffineWrapper<C::Affine>) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<C: CurveGroup> Sub<ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn sub(self, other: ArkAffineWrapper<C::Affine>) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<C: CurveGroup> Sub<&ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn sub(self, other: &ArkAffineWrapper<C::Affine>) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<C: CurveGroup> AddAssign<ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    fn add_assign(&mut self, other: ArkAffineWrapper<C::Affine>) {
+        self.0 += other.0;
+    }
+}
+
+impl<C: CurveGroup> AddAssign<&ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    fn add_assign(&mut self, other: &ArkAffineWrapper<C::Affine>) {
+        self.0 += other.0;
+    }
+}
+
+impl<C: CurveGroup> SubAssign<ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    fn sub_assign(&mut self, other: ArkAffineWrapper<C::Affine>) {
+        self.0 -= other.0;
+    }
+}
+
+impl<C: CurveGroup> SubAssign<&ArkAffineWrapper<C::Affine>> for ArkGroupWrapper<C> {
+    fn sub_assign(&mut self, other: &ArkAffineWrapper<C::Affine>) {
+        self.0 -= other.0;
+    }
+}
+
+fn from_group<A: AffineRepr>(
+    point: CtOption<ArkGroupWrapper<A::Group>>,
+) -> CtOption<ArkAffineWrapper<A>> {
+    match Option::<ArkGroupWrapper<A::Group>>::from(point) {
+        Some(point) => CtOption::new(ArkAffineWrapper::new(point.0.into()), Choice::from(1)),
+        None => CtOption::new(ArkAffineWrapper::new(A::zero()), Choice::from(0)),
+    }
+}
+
+// The affine encodings are the same bytes as the projective ones
+impl<A: AffineRepr> GroupEncoding for ArkAffineWrapper<A>
+where
+    ArkGroupWrapper<A::Group>: GroupEncoding,
+{
+    type Repr = <ArkGroupWrapper<A::Group> as GroupEncoding>::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        from_group(ArkGroupWrapper::<A::Group>::from_bytes(bytes))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        from_group(ArkGroupWrapper::<A::Group>::from_bytes_unchecked(bytes))
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        ArkGroupWrapper::new(self.0.into_group()).to_bytes()
+    }
+}
+
+impl<A: AffineRepr> UncompressedEncoding for ArkAffineWrapper<A>
+where
+    ArkGroupWrapper<A::Group>: UncompressedEncoding,
+{
+    type Uncompressed = <ArkGroupWrapper<A::Group> as UncompressedEncoding>::Uncompressed;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        from_group(ArkGroupWrapper::<A::Group>::from_uncompressed(bytes))
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        from_group(ArkGroupWrapper::<A::Group>::from_uncompressed_unchecked(
+            bytes,
+        ))
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        ArkGroupWrapper::new(self.0.into_group()).to_uncompressed()
+    }
+}
+
+impl<C: CurveGroup> Curve for ArkGroupWrapper<C>
+where
+    Self: Group,
+{
+    type AffineRepr = ArkAffineWrapper<C::Affine>;
+
+    fn batch_normalize(points: &[Self], affines: &mut [Self::AffineRepr]) {
+        assert_eq!(points.len(), affines.len());
+        let points: Vec<C> = points.iter().map(|point| point.0).collect();
+        for (affine, point) in affines.iter_mut().zip(C::normalize_batch(&points)) {
+            *affine = ArkAffineWrapper::new(point);
+        }
+    }
+
+    fn to_affine(&self) -> Self::AffineRepr {
+        ArkAffineWrapper::new(self.0.into_affine())
+    }
+}
+
+// `PrimeGroup` only holds for points built through the checked constructors, as documented on
+// `ArkGroupWrapper`. `ArkGroupWrapper::new` and the unchecked decoders can hold other points.
+impl<C: CurveGroup> PrimeGroup for ArkGroupWrapper<C> where Self: Group + GroupEncoding {}
+
+// Points built through the unchecked constructors can have a torsion component, which is what
+// `clear_cofactor` removes, e.g. from the output of `hash2curve::MapToCurve`.
+impl<C: CurveGroup> CofactorGroup for ArkGroupWrapper<C>
+where
+    Self: PrimeGroup,
+{
+    type Subgroup = Self;
+
+    fn clear_cofactor(&self) -> Self {
+        Self::new(self.0.into_affine().clear_cofactor().into_group())
+    }
+
+    fn into_subgroup(self) -> CtOption<Self> {
+        let is_torsion_free = self.is_torsion_free();
+        CtOption::new(self, is_torsion_free)
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time scalar
+        // multiplication
+        let torsion = self.0.into_affine().mul_bigint(C::ScalarField::MODULUS);
+        Choice::from(torsion.is_zero() as u8)
+    }
+}
+
+impl<C: CurveGroup> PrimeCurve for ArkGroupWrapper<C>
+where
+    Self: PrimeGroup<Scalar = ArkScalarWrapper<C::ScalarField>>,
+    ArkScalarWrapper<C::ScalarField>: ff::PrimeField,
+    ArkAffineWrapper<C::Affine>: GroupEncoding,
+{
+    type Affine = ArkAffineWrapper<C::Affine>;
+}
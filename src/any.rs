@@ -0,0 +1,501 @@
+//! Points and scalars on a curve chosen at runtime.
+//!
+//! [`ArkCurveId`] names every curve that this crate implements the group encodings for, and
+//! [`AnyArkPoint`]/[`AnyArkScalar`] hold a point or scalar of any of them. Arithmetic between
+//! values of different curves returns [`AnyCurveError::CurveMismatch`] instead of panicking, and
+//! the byte encodings are prefixed with the curve's tag so that they decode to the same curve.
+//! Generic code over `elliptic_curve::Group` runs on a runtime curve with [`with_curve!`].
+
+use crate::{ArkGroupWrapper, ArkScalarWrapper};
+use ark_ec::{short_weierstrass, twisted_edwards};
+use core::fmt;
+use core::str::FromStr;
+use elliptic_curve::group::{Group, GroupEncoding};
+use ff::{Field, PrimeField};
+
+/// Errors from [`AnyArkPoint`] and [`AnyArkScalar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnyCurveError {
+    /// The operands live on different curves.
+    CurveMismatch {
+        expected: ArkCurveId,
+        found: ArkCurveId,
+    },
+    /// The tag or name does not belong to any supported curve.
+    UnknownCurve,
+    /// The bytes are not a valid encoding on the tagged curve.
+    InvalidEncoding,
+}
+
+impl fmt::Display for AnyCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CurveMismatch { expected, found } => {
+                write!(f, "expected a value on {expected}, found one on {found}")
+            }
+            Self::UnknownCurve => f.write_str("unknown curve"),
+            Self::InvalidEncoding => f.write_str("invalid encoding"),
+        }
+    }
+}
+
+impl std::error::Error for AnyCurveError {}
+
+/// A curve of [`ArkCurveId`], as the `ArkGroupWrapper` that [`with_curve!`] binds.
+pub trait AnyCurve: Group + GroupEncoding {
+    const CURVE: ArkCurveId;
+
+    fn into_any(self) -> AnyArkPoint;
+
+    fn from_any(point: AnyArkPoint) -> Option<Self>;
+
+    fn scalar_into_any(scalar: Self::Scalar) -> AnyArkScalar;
+
+    fn scalar_from_any(scalar: AnyArkScalar) -> Option<Self::Scalar>;
+}
+
+/// Reads a `G` encoding from the bytes after the curve tag.
+fn point_from_bytes<G: GroupEncoding>(bytes: &[u8]) -> Result<G, AnyCurveError> {
+    let mut repr = G::Repr::default();
+    if repr.as_ref().len() != bytes.len() {
+        return Err(AnyCurveError::InvalidEncoding);
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::from(G::from_bytes(&repr)).ok_or(AnyCurveError::InvalidEncoding)
+}
+
+/// Reads an `F` repr from the bytes after the curve tag.
+fn scalar_from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, AnyCurveError> {
+    let mut repr = F::Repr::default();
+    if repr.as_ref().len() != bytes.len() {
+        return Err(AnyCurveError::InvalidEncoding);
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::from(F::from_repr(repr)).ok_or(AnyCurveError::InvalidEncoding)
+}
+
+fn tagged(curve: ArkCurveId, bytes: &[u8]) -> Vec<u8> {
+    let mut tagged = Vec::with_capacity(1 + bytes.len());
+    tagged.push(curve.tag());
+    tagged.extend_from_slice(bytes);
+    tagged
+}
+
+fn untagged(bytes: &[u8]) -> Result<(ArkCurveId, &[u8]), AnyCurveError> {
+    let (&tag, bytes) = bytes.split_first().ok_or(AnyCurveError::InvalidEncoding)?;
+    Ok((
+        ArkCurveId::from_tag(tag).ok_or(AnyCurveError::UnknownCurve)?,
+        bytes,
+    ))
+}
+
+macro_rules! any_curves {
+    ($($(#[$doc:meta])* $id:ident = $tag:literal, $name:literal => $curve:ty;)*) => {
+        /// A curve supported by [`AnyArkPoint`] and [`AnyArkScalar`]. The discriminant is the
+        /// tag that prefixes their encodings, and the name is what `Display` and `FromStr` use.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(u8)]
+        pub enum ArkCurveId {
+            $($(#[$doc])* $id = $tag,)*
+        }
+
+        impl ArkCurveId {
+            /// Every supported curve, in tag order.
+            pub const ALL: &'static [Self] = &[$(Self::$id),*];
+
+            /// The tag that prefixes encodings of points and scalars on this curve.
+            pub const fn tag(self) -> u8 {
+                self as u8
+            }
+
+            pub const fn from_tag(tag: u8) -> Option<Self> {
+                match tag {
+                    $($tag => Some(Self::$id),)*
+                    _ => None,
+                }
+            }
+
+            /// The lowercase name used in configuration, such as `"bls12_381_g1"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$id => $name,)*
+                }
+            }
+        }
+
+        impl FromStr for ArkCurveId {
+            type Err = AnyCurveError;
+
+            fn from_str(s: &str) -> Result<Self, AnyCurveError> {
+                match s {
+                    $($name => Ok(Self::$id),)*
+                    _ => Err(AnyCurveError::UnknownCurve),
+                }
+            }
+        }
+
+        /// The group wrapper of each [`ArkCurveId`], under the same name.
+        pub mod curves {
+            use super::*;
+            $(pub type $id = ArkGroupWrapper<$curve>;)*
+        }
+
+        /// A point on a curve chosen at runtime.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum AnyArkPoint {
+            $($id(curves::$id),)*
+        }
+
+        /// A scalar of a curve chosen at runtime.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum AnyArkScalar {
+            $($id(<curves::$id as Group>::Scalar),)*
+        }
+
+        $(
+            impl AnyCurve for curves::$id {
+                const CURVE: ArkCurveId = ArkCurveId::$id;
+
+                fn into_any(self) -> AnyArkPoint {
+                    AnyArkPoint::$id(self)
+                }
+
+                fn from_any(point: AnyArkPoint) -> Option<Self> {
+                    match point {
+                        AnyArkPoint::$id(point) => Some(point),
+                        _ => None,
+                    }
+                }
+
+                fn scalar_into_any(scalar: Self::Scalar) -> AnyArkScalar {
+                    AnyArkScalar::$id(scalar)
+                }
+
+                fn scalar_from_any(scalar: AnyArkScalar) -> Option<Self::Scalar> {
+                    match scalar {
+                        AnyArkScalar::$id(scalar) => Some(scalar),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl From<curves::$id> for AnyArkPoint {
+                fn from(point: curves::$id) -> Self {
+                    Self::$id(point)
+                }
+            }
+
+            impl TryFrom<AnyArkPoint> for curves::$id {
+                type Error = AnyCurveError;
+
+                fn try_from(point: AnyArkPoint) -> Result<Self, AnyCurveError> {
+                    match point {
+                        AnyArkPoint::$id(point) => Ok(point),
+                        other => Err(AnyCurveError::CurveMismatch {
+                            expected: ArkCurveId::$id,
+                            found: other.curve(),
+                        }),
+                    }
+                }
+            }
+        )*
+
+        impl AnyArkPoint {
+            pub fn curve(&self) -> ArkCurveId {
+                match self {
+                    $(Self::$id(_) => ArkCurveId::$id,)*
+                }
+            }
+
+            pub fn identity(curve: ArkCurveId) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Group::identity()),)*
+                }
+            }
+
+            pub fn generator(curve: ArkCurveId) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Group::generator()),)*
+                }
+            }
+
+            pub fn random(curve: ArkCurveId, rng: impl rand_core::RngCore) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Group::random(rng)),)*
+                }
+            }
+
+            pub fn is_identity(&self) -> bool {
+                match self {
+                    $(Self::$id(point) => point.is_identity().into(),)*
+                }
+            }
+
+            pub fn double(&self) -> Self {
+                match self {
+                    $(Self::$id(point) => Self::$id(point.double()),)*
+                }
+            }
+
+            pub fn neg(&self) -> Self {
+                match self {
+                    $(Self::$id(point) => Self::$id(-*point),)*
+                }
+            }
+
+            pub fn checked_add(&self, other: &Self) -> Result<Self, AnyCurveError> {
+                match (self, other) {
+                    $((Self::$id(a), Self::$id(b)) => Ok(Self::$id(*a + b)),)*
+                    _ => Err(self.mismatch(other.curve())),
+                }
+            }
+
+            pub fn checked_sub(&self, other: &Self) -> Result<Self, AnyCurveError> {
+                match (self, other) {
+                    $((Self::$id(a), Self::$id(b)) => Ok(Self::$id(*a - b)),)*
+                    _ => Err(self.mismatch(other.curve())),
+                }
+            }
+
+            pub fn checked_mul(&self, scalar: &AnyArkScalar) -> Result<Self, AnyCurveError> {
+                match (self, scalar) {
+                    $((Self::$id(a), AnyArkScalar::$id(b)) => Ok(Self::$id(*a * b)),)*
+                    _ => Err(self.mismatch(scalar.curve())),
+                }
+            }
+
+            /// The curve tag followed by the `GroupEncoding` bytes.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match self {
+                    $(Self::$id(point) => tagged(ArkCurveId::$id, point.to_bytes().as_ref()),)*
+                }
+            }
+
+            /// Decodes the output of [`Self::to_bytes`], with the checks of the curve's
+            /// `GroupEncoding::from_bytes`.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, AnyCurveError> {
+                let (curve, bytes) = untagged(bytes)?;
+                match curve {
+                    $(ArkCurveId::$id => point_from_bytes(bytes).map(Self::$id),)*
+                }
+            }
+        }
+
+        impl AnyArkScalar {
+            pub fn curve(&self) -> ArkCurveId {
+                match self {
+                    $(Self::$id(_) => ArkCurveId::$id,)*
+                }
+            }
+
+            pub fn zero(curve: ArkCurveId) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Field::ZERO),)*
+                }
+            }
+
+            pub fn one(curve: ArkCurveId) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Field::ONE),)*
+                }
+            }
+
+            pub fn from_u64(curve: ArkCurveId, value: u64) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(ArkScalarWrapper::from(value)),)*
+                }
+            }
+
+            pub fn random(curve: ArkCurveId, rng: impl rand_core::RngCore) -> Self {
+                match curve {
+                    $(ArkCurveId::$id => Self::$id(Field::random(rng)),)*
+                }
+            }
+
+            pub fn is_zero(&self) -> bool {
+                match self {
+                    $(Self::$id(scalar) => scalar.is_zero().into(),)*
+                }
+            }
+
+            pub fn neg(&self) -> Self {
+                match self {
+                    $(Self::$id(scalar) => Self::$id(-*scalar),)*
+                }
+            }
+
+            /// Returns `None` for zero.
+            pub fn invert(&self) -> Option<Self> {
+                match self {
+                    $(Self::$id(scalar) => Option::from(scalar.invert()).map(Self::$id),)*
+                }
+            }
+
+            pub fn checked_add(&self, other: &Self) -> Result<Self, AnyCurveError> {
+                match (self, other) {
+                    $((Self::$id(a), Self::$id(b)) => Ok(Self::$id(*a + b)),)*
+                    _ => Err(self.mismatch(other.curve())),
+                }
+            }
+
+            pub fn checked_sub(&self, other: &Self) -> Result<Self, AnyCurveError> {
+                match (self, other) {
+                    $((Self::$id(a), Self::$id(b)) => Ok(Self::$id(*a - b)),)*
+                    _ => Err(self.mismatch(other.curve())),
+                }
+            }
+
+            pub fn checked_mul(&self, other: &Self) -> Result<Self, AnyCurveError> {
+                match (self, other) {
+                    $((Self::$id(a), Self::$id(b)) => Ok(Self::$id(*a * b)),)*
+                    _ => Err(self.mismatch(other.curve())),
+                }
+            }
+
+            /// The curve tag followed by the `PrimeField::to_repr` bytes.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match self {
+                    $(Self::$id(scalar) => tagged(ArkCurveId::$id, scalar.to_repr().as_ref()),)*
+                }
+            }
+
+            /// Decodes the output of [`Self::to_bytes`], rejecting unreduced scalars.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, AnyCurveError> {
+                let (curve, bytes) = untagged(bytes)?;
+                match curve {
+                    $(ArkCurveId::$id => scalar_from_bytes(bytes).map(Self::$id),)*
+                }
+            }
+        }
+    };
+}
+
+any_curves! {
+    Secp256k1 = 1, "secp256k1" => ark_secp256k1::Projective;
+    /// NIST P-256.
+    Secp256r1 = 2, "secp256r1" => ark_secp256r1::Projective;
+    /// NIST P-384.
+    Secp384r1 = 3, "secp384r1" => ark_secp384r1::Projective;
+    Secq256k1 = 4, "secq256k1" => ark_secq256k1::Projective;
+    /// Edwards25519, with the RFC 8032 point encoding.
+    Ed25519 = 5, "ed25519" => ark_ed25519::EdwardsProjective;
+    Curve25519 = 6, "curve25519" => ark_curve25519::EdwardsProjective;
+    Pallas = 7, "pallas" => ark_pallas::Projective;
+    Vesta = 8, "vesta" => ark_vesta::Projective;
+    Grumpkin = 9, "grumpkin" => ark_grumpkin::Projective;
+    Bls12_381G1 = 16, "bls12_381_g1" => short_weierstrass::Projective<ark_bls12_381::g1::Config>;
+    Bls12_381G2 = 17, "bls12_381_g2" => short_weierstrass::Projective<ark_bls12_381::g2::Config>;
+    Bls12_377G1 = 18, "bls12_377_g1" => short_weierstrass::Projective<ark_bls12_377::g1::Config>;
+    Bls12_377G2 = 19, "bls12_377_g2" => short_weierstrass::Projective<ark_bls12_377::g2::Config>;
+    Bn254G1 = 20, "bn254_g1" => short_weierstrass::Projective<ark_bn254::g1::Config>;
+    Bn254G2 = 21, "bn254_g2" => short_weierstrass::Projective<ark_bn254::g2::Config>;
+    Bw6_761G1 = 22, "bw6_761_g1" => short_weierstrass::Projective<ark_bw6_761::g1::Config>;
+    Bw6_761G2 = 23, "bw6_761_g2" => short_weierstrass::Projective<ark_bw6_761::g2::Config>;
+    Bw6_767G1 = 24, "bw6_767_g1" => short_weierstrass::Projective<ark_bw6_767::g1::Config>;
+    Bw6_767G2 = 25, "bw6_767_g2" => short_weierstrass::Projective<ark_bw6_767::g2::Config>;
+    Cp6_782G1 = 26, "cp6_782_g1" => short_weierstrass::Projective<ark_cp6_782::g1::Config>;
+    Cp6_782G2 = 27, "cp6_782_g2" => short_weierstrass::Projective<ark_cp6_782::g2::Config>;
+    Mnt4_298G1 = 28, "mnt4_298_g1" => short_weierstrass::Projective<ark_mnt4_298::g1::Config>;
+    Mnt4_298G2 = 29, "mnt4_298_g2" => short_weierstrass::Projective<ark_mnt4_298::g2::Config>;
+    Mnt6_298G1 = 30, "mnt6_298_g1" => short_weierstrass::Projective<ark_mnt6_298::g1::Config>;
+    Mnt6_298G2 = 31, "mnt6_298_g2" => short_weierstrass::Projective<ark_mnt6_298::g2::Config>;
+    Mnt4_753G1 = 32, "mnt4_753_g1" => short_weierstrass::Projective<ark_mnt4_753::g1::Config>;
+    Mnt4_753G2 = 33, "mnt4_753_g2" => short_weierstrass::Projective<ark_mnt4_753::g2::Config>;
+    Mnt6_753G1 = 34, "mnt6_753_g1" => short_weierstrass::Projective<ark_mnt6_753::g1::Config>;
+    Mnt6_753G2 = 35, "mnt6_753_g2" => short_weierstrass::Projective<ark_mnt6_753::g2::Config>;
+    EdOnBls12_377 = 48, "ed_on_bls12_377" =>
+        twisted_edwards::Projective<ark_ed_on_bls12_377::EdwardsConfig>;
+    /// Jubjub.
+    EdOnBls12_381 = 49, "ed_on_bls12_381" =>
+        twisted_edwards::Projective<ark_ed_on_bls12_381::JubjubConfig>;
+    Bandersnatch = 50, "bandersnatch" =>
+        twisted_edwards::Projective<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig>;
+    /// Baby Jubjub.
+    EdOnBn254 = 51, "ed_on_bn254" => twisted_edwards::Projective<ark_ed_on_bn254::EdwardsConfig>;
+    /// Also the curve of `ark_ed_on_bw6_761`.
+    EdOnCp6_782 = 52, "ed_on_cp6_782" =>
+        twisted_edwards::Projective<ark_ed_on_cp6_782::EdwardsConfig>;
+    EdOnMnt4_298 = 53, "ed_on_mnt4_298" =>
+        twisted_edwards::Projective<ark_ed_on_mnt4_298::EdwardsConfig>;
+    EdOnMnt4_753 = 54, "ed_on_mnt4_753" =>
+        twisted_edwards::Projective<ark_ed_on_mnt4_753::EdwardsConfig>;
+}
+
+impl fmt::Display for ArkCurveId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AnyArkPoint {
+    pub fn new<G: AnyCurve>(point: G) -> Self {
+        point.into_any()
+    }
+
+    /// Returns the point as a `G`, or `None` if it lies on another curve.
+    pub fn downcast<G: AnyCurve>(self) -> Option<G> {
+        G::from_any(self)
+    }
+
+    fn mismatch(&self, found: ArkCurveId) -> AnyCurveError {
+        AnyCurveError::CurveMismatch {
+            expected: self.curve(),
+            found,
+        }
+    }
+}
+
+impl AnyArkScalar {
+    pub fn new<G: AnyCurve>(scalar: G::Scalar) -> Self {
+        G::scalar_into_any(scalar)
+    }
+
+    /// Returns the scalar as a scalar of `G`, or `None` if it belongs to another curve.
+    pub fn downcast<G: AnyCurve>(self) -> Option<G::Scalar> {
+        G::scalar_from_any(self)
+    }
+
+    fn mismatch(&self, found: ArkCurveId) -> AnyCurveError {
+        AnyCurveError::CurveMismatch {
+            expected: self.curve(),
+            found,
+        }
+    }
+}
+
+/// Runs `body` with `G` bound to the group wrapper of a runtime [`ArkCurveId`], so generic code
+/// over `elliptic_curve::Group` (and [`AnyCurve`], to convert from and to [`AnyArkPoint`] and
+/// [`AnyArkScalar`]) runs on any supported curve. Every arm must have the same type.
+///
+/// ```
+/// use ark_elliptic_curve_groups::any::{AnyArkPoint, AnyCurve, ArkCurveId};
+/// use ark_elliptic_curve_groups::with_curve;
+/// use elliptic_curve::Group;
+///
+/// let curve: ArkCurveId = "bls12_381_g1".parse().unwrap();
+/// let point = with_curve!(curve, |G| AnyArkPoint::new(G::generator().double()));
+/// assert_eq!(point, AnyArkPoint::generator(curve).double());
+/// ```
+#[macro_export]
+macro_rules! with_curve {
+    ($curve:expr, |$group:ident| $body:expr) => {
+        $crate::with_curve!(@dispatch $curve, $group, $body, [
+            Secp256k1, Secp256r1, Secp384r1, Secq256k1, Ed25519, Curve25519, Pallas, Vesta,
+            Grumpkin, Bls12_381G1, Bls12_381G2, Bls12_377G1, Bls12_377G2, Bn254G1, Bn254G2,
+            Bw6_761G1, Bw6_761G2, Bw6_767G1, Bw6_767G2, Cp6_782G1, Cp6_782G2, Mnt4_298G1,
+            Mnt4_298G2, Mnt6_298G1, Mnt6_298G2, Mnt4_753G1, Mnt4_753G2, Mnt6_753G1, Mnt6_753G2,
+            EdOnBls12_377, EdOnBls12_381, Bandersnatch, EdOnBn254, EdOnCp6_782, EdOnMnt4_298,
+            EdOnMnt4_753,
+        ])
+    };
+    // The match has no wildcard arm, so a curve missing from the list above fails to compile
+    (@dispatch $curve:expr, $group:ident, $body:expr, [$($id:ident,)*]) => {
+        match $curve {
+            $($crate::any::ArkCurveId::$id => {
+                #[allow(dead_code)]
+                type $group = $crate::any::curves::$id;
+                $body
+            })*
+        }
+    };
+}
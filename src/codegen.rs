@@ -0,0 +1,66 @@
+// Shared with build.rs through `include!`, so this file must not contain inner attributes or
+// inner doc comments.
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::Field;
+use ark_serialize::CanonicalSerialize;
+use std::io::Write;
+
+/// Writes a `pub mod {prefix}` with the point sizes that [`crate::wrap_curve!`] reads, the
+/// compressed generator, and the base field extension degree for the [`crate::info::CurveInfo`]
+/// registry.
+pub fn generate_curve_constants<G: CurveGroup>(f: &mut impl Write, prefix: &str) {
+    writeln!(f, "#[allow(dead_code)]").unwrap();
+    writeln!(f, "pub mod {} {{", prefix).unwrap();
+
+    let generator = G::generator();
+    let mut buf = Vec::new();
+    generator.serialize_compressed(&mut buf).unwrap();
+    let compressed_size = <G as CanonicalSerialize>::compressed_size(&generator);
+    let uncompressed_size = <G as CanonicalSerialize>::uncompressed_size(&generator);
+
+    writeln!(f, "    #[allow(dead_code)]").unwrap();
+    writeln!(
+        f,
+        "    pub const COMPRESSED_POINT_SIZE: usize = {};",
+        compressed_size
+    )
+    .unwrap();
+    writeln!(f, "    #[allow(dead_code)]").unwrap();
+    writeln!(
+        f,
+        "    pub const UNCOMPRESSED_POINT_SIZE: usize = {};",
+        uncompressed_size
+    )
+    .unwrap();
+    writeln!(f, "    #[allow(dead_code)]").unwrap();
+    writeln!(f, "    pub const GENERATOR_COMPRESSED: &[u8] = &{:?};", buf).unwrap();
+    writeln!(f, "    #[allow(dead_code)]").unwrap();
+    writeln!(
+        f,
+        "    pub const BASE_FIELD_DEGREE: u64 = {};",
+        G::BaseField::extension_degree()
+    )
+    .unwrap();
+
+    writeln!(f, "}}").unwrap();
+}
+
+/// Writes a `pub mod {prefix}` with the compressed size of the pairing's target group.
+pub fn generate_gt_constants<P: Pairing>(f: &mut impl Write, prefix: &str) {
+    writeln!(f, "#[allow(dead_code)]").unwrap();
+    writeln!(f, "pub mod {} {{", prefix).unwrap();
+
+    let generator = PairingOutput::<P>::generator();
+    let compressed_size = generator.compressed_size();
+
+    writeln!(f, "    #[allow(dead_code)]").unwrap();
+    writeln!(
+        f,
+        "    pub const COMPRESSED_GT_SIZE: usize = {};",
+        compressed_size
+    )
+    .unwrap();
+
+    writeln!(f, "}}").unwrap();
+}
@@ -0,0 +1,100 @@
+//! RFC 8032 point encoding for Ed25519.
+//!
+//! Arkworks compresses twisted Edwards points to the y-coordinate with a flag that records
+//! whether x is the larger of `x` and `-x`. RFC 8032 instead stores the parity of x in the top
+//! bit of the little-endian y-coordinate, which is what every Ed25519 verifier expects, so the
+//! Ed25519 wrapper implements `GroupEncoding` with that format.
+
+use crate::ArkGroupWrapper;
+use crate::constants::ed25519_projective_constants;
+use crate::group::{PointRepr, UncompressedPointRepr, from_uncompressed_bytes, to_repr};
+use ark_ec::twisted_edwards::Affine;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ed25519::{EdwardsConfig, EdwardsProjective, Fq};
+use ark_ff::{BigInt, BigInteger, PrimeField as ArkPrimeField, Zero};
+use ark_serialize::{Compress, Validate};
+use elliptic_curve::group::{GroupEncoding, UncompressedEncoding};
+use subtle::{Choice, CtOption};
+
+/// Size of an RFC 8032 encoded point: the 255-bit y-coordinate plus the sign bit of x.
+pub const ENCODED_POINT_SIZE: usize = 32;
+
+fn encode_point(point: &EdwardsProjective) -> [u8; ENCODED_POINT_SIZE] {
+    let affine = point.into_affine();
+    let mut out = [0u8; ENCODED_POINT_SIZE];
+    for (chunk, limb) in out.chunks_mut(8).zip(affine.y.into_bigint().as_ref()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    out[ENCODED_POINT_SIZE - 1] |= (affine.x.into_bigint().is_odd() as u8) << 7;
+    out
+}
+
+fn decode_point(bytes: &[u8; ENCODED_POINT_SIZE]) -> Option<EdwardsProjective> {
+    let x_is_odd = bytes[ENCODED_POINT_SIZE - 1] >> 7 == 1;
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs[3] &= u64::MAX >> 1;
+    // `from_bigint` rejects y-coordinates that are not reduced modulo the field characteristic
+    let y = Fq::from_bigint(BigInt::new(limbs))?;
+    let (x, neg_x) = Affine::<EdwardsConfig>::get_xs_from_y_unchecked(y)?;
+    // RFC 8032 rejects a set sign bit when x is zero, as `-0` has no distinct encoding
+    if x.is_zero() && x_is_odd {
+        return None;
+    }
+    let x = if x.into_bigint().is_odd() == x_is_odd {
+        x
+    } else {
+        neg_x
+    };
+    Some(Affine::<EdwardsConfig>::new_unchecked(x, y))
+        .filter(Affine::is_in_correct_subgroup_assuming_on_curve)
+        .map(Affine::into_group)
+}
+
+impl GroupEncoding for ArkGroupWrapper<EdwardsProjective> {
+    type Repr = PointRepr<ENCODED_POINT_SIZE>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time point recovery
+        // Unlike plain RFC 8032 decoding, points with a small-order component are rejected so that
+        // every decoded value is in the prime-order subgroup the `Group` impl works in.
+        let mut encoded = [0u8; ENCODED_POINT_SIZE];
+        encoded.copy_from_slice(bytes.as_ref());
+        match decode_point(&encoded) {
+            Some(point) => CtOption::new(Self::new(point), Choice::from(1)),
+            None => CtOption::new(Self::new(EdwardsProjective::zero()), Choice::from(0)),
+        }
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let mut repr = Self::Repr::default();
+        repr.as_mut().copy_from_slice(&encode_point(&self.0));
+        repr
+    }
+}
+
+// Ed25519 keeps the arkworks uncompressed serialisation, which has no RFC 8032 counterpart. It is
+// implemented here rather than through `ArkEncoding`, which would also bring the arkworks
+// `GroupEncoding`.
+impl UncompressedEncoding for ArkGroupWrapper<EdwardsProjective> {
+    type Uncompressed =
+        UncompressedPointRepr<{ ed25519_projective_constants::UNCOMPRESSED_POINT_SIZE }>;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        from_uncompressed_bytes(bytes.as_ref(), Validate::Yes, Affine::is_on_curve)
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        from_uncompressed_bytes(bytes.as_ref(), Validate::No, Affine::is_on_curve)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        to_repr(&self.0, Compress::No)
+    }
+}
@@ -0,0 +1,133 @@
+//! Point encodings expected by the Ethereum precompiles.
+//!
+//! BN254 points use the layout of the `ecAdd`/`ecMul`/`ecPairing` precompiles (EIP-196/197):
+//! 32-byte big-endian coordinates, with `Fq2` elements written imaginary part first. BLS12-381
+//! points use the layout of EIP-2537: every base field element is padded to 64 bytes, and `Fq2`
+//! elements are written real part first. In both cases the point at infinity is all zeros.
+
+use crate::ArkGroupWrapper;
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField, QuadExtConfig, QuadExtField, Zero};
+use subtle::{Choice, CtOption};
+
+/// Writes `element` big-endian into `out`, left-padded with zeros.
+fn write_fp<F: ArkPrimeField>(out: &mut [u8], element: &F) {
+    let bytes = element.into_bigint().to_bytes_be();
+    out.fill(0);
+    let offset = out.len() - bytes.len();
+    out[offset..].copy_from_slice(&bytes);
+}
+
+/// Reads a big-endian field element, rejecting non-zero padding and values that are not reduced.
+fn read_fp<F: ArkPrimeField>(bytes: &[u8]) -> Option<F> {
+    let element = F::from_be_bytes_mod_order(bytes);
+    let mut canonical = [0u8; 64];
+    let canonical = &mut canonical[..bytes.len()];
+    write_fp(canonical, &element);
+    (canonical == bytes).then_some(element)
+}
+
+fn write_fp2_imaginary_first<P>(out: &mut [u8], element: &QuadExtField<P>)
+where
+    P: QuadExtConfig,
+    P::BaseField: ArkPrimeField,
+{
+    let (c1, c0) = out.split_at_mut(out.len() / 2);
+    write_fp(c1, &element.c1);
+    write_fp(c0, &element.c0);
+}
+
+fn read_fp2_imaginary_first<P>(bytes: &[u8]) -> Option<QuadExtField<P>>
+where
+    P: QuadExtConfig,
+    P::BaseField: ArkPrimeField,
+{
+    let (c1, c0) = bytes.split_at(bytes.len() / 2);
+    Some(QuadExtField::new(read_fp(c0)?, read_fp(c1)?))
+}
+
+fn write_fp2_real_first<P>(out: &mut [u8], element: &QuadExtField<P>)
+where
+    P: QuadExtConfig,
+    P::BaseField: ArkPrimeField,
+{
+    let (c0, c1) = out.split_at_mut(out.len() / 2);
+    write_fp(c0, &element.c0);
+    write_fp(c1, &element.c1);
+}
+
+fn read_fp2_real_first<P>(bytes: &[u8]) -> Option<QuadExtField<P>>
+where
+    P: QuadExtConfig,
+    P::BaseField: ArkPrimeField,
+{
+    let (c0, c1) = bytes.split_at(bytes.len() / 2);
+    Some(QuadExtField::new(read_fp(c0)?, read_fp(c1)?))
+}
+
+fn encode_point<P: SWCurveConfig>(
+    point: &Projective<P>,
+    out: &mut [u8],
+    write: impl Fn(&mut [u8], &P::BaseField),
+) {
+    // The point at infinity is encoded as all zeros, which is what `out` already holds
+    if let Some((x, y)) = point.into_affine().xy() {
+        let (x_bytes, y_bytes) = out.split_at_mut(out.len() / 2);
+        write(x_bytes, &x);
+        write(y_bytes, &y);
+    }
+}
+
+fn decode_point<P: SWCurveConfig>(
+    bytes: &[u8],
+    read: impl Fn(&[u8]) -> Option<P::BaseField>,
+) -> Option<Projective<P>> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Some(Projective::<P>::zero());
+    }
+    let (x_bytes, y_bytes) = bytes.split_at(bytes.len() / 2);
+    let point = Affine::<P>::new_unchecked(read(x_bytes)?, read(y_bytes)?);
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve())
+        .then(|| point.into_group())
+}
+
+macro_rules! impl_evm_encoding {
+    ($config:ty, $size:literal, $write:expr, $read:expr) => {
+        impl ArkGroupWrapper<Projective<$config>> {
+            /// Encodes the point in the layout the Ethereum precompiles expect.
+            pub fn to_evm_bytes(&self) -> [u8; $size] {
+                let mut out = [0u8; $size];
+                encode_point(&self.0, &mut out, $write);
+                out
+            }
+
+            /// Decodes a point in the Ethereum precompile layout, checking that the coordinates
+            /// are canonical and that the point is on the curve and in the prime-order subgroup.
+            pub fn from_evm_bytes(bytes: &[u8; $size]) -> CtOption<Self> {
+                // Note: This is not constant-time, but arkworks doesn't provide constant-time point recovery
+                match decode_point::<$config>(bytes, $read) {
+                    Some(point) => CtOption::new(Self::new(point), Choice::from(1)),
+                    None => {
+                        CtOption::new(Self::new(Projective::<$config>::zero()), Choice::from(0))
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_evm_encoding!(ark_bn254::g1::Config, 64, write_fp, read_fp);
+impl_evm_encoding!(
+    ark_bn254::g2::Config,
+    128,
+    write_fp2_imaginary_first,
+    read_fp2_imaginary_first
+);
+impl_evm_encoding!(ark_bls12_381::g1::Config, 128, write_fp, read_fp);
+impl_evm_encoding!(
+    ark_bls12_381::g2::Config,
+    256,
+    write_fp2_real_first,
+    read_fp2_real_first
+);
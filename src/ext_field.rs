@@ -0,0 +1,354 @@
+use ark_ff::{Field as ArkField, LegendreSymbol};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Wraps an element of any arkworks field, typically an extension field such as `Fq2`, `Fq6` or
+/// `Fq12`, to implement `ff::Field`.
+///
+/// Extension fields have no `ff::PrimeField` representation, so only `ff::Field` is implemented.
+/// Square roots use Tonelli-Shanks over the full field order, because arkworks leaves them
+/// unimplemented for some towers such as `Fq6`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Copy)]
+pub struct ArkExtFieldWrapper<F: ArkField>(pub(crate) F);
+
+impl<F: ArkField> ArkExtFieldWrapper<F> {
+    pub const fn new(element: F) -> Self {
+        Self(element)
+    }
+
+    pub const fn into_inner(self) -> F {
+        self.0
+    }
+
+    /// Returns the multiplicative inverse, if the element is non-zero.
+    pub fn invert(&self) -> CtOption<Self> {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time inversion
+        match self.0.inverse() {
+            Some(inverse) => CtOption::new(Self::new(inverse), Choice::from(1)),
+            None => CtOption::new(Self::new(F::ZERO), Choice::from(0)),
+        }
+    }
+
+    /// Returns a square root, if the element is a square.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time square roots
+        match tonelli_shanks(&self.0) {
+            Some(root) => CtOption::new(Self::new(root), Choice::from(1)),
+            None => CtOption::new(Self::new(F::ZERO), Choice::from(0)),
+        }
+    }
+
+    /// Raises the element to the `power`-th power of the characteristic.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Self::new(self.0.frobenius_map(power))
+    }
+
+    /// Encodes the element with the arkworks compressed serialisation.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.0.compressed_size());
+        // Note: This unwrap is safe because writing to a `Vec` cannot fail
+        self.0.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Decodes an element written by [`Self::to_bytes`], rejecting trailing bytes and
+    /// non-canonical coefficients.
+    pub fn from_bytes(bytes: &[u8]) -> CtOption<Self> {
+        let mut reader = bytes;
+        match F::deserialize_compressed(&mut reader) {
+            Ok(element) if reader.is_empty() => CtOption::new(Self::new(element), Choice::from(1)),
+            _ => CtOption::new(Self::new(F::ZERO), Choice::from(0)),
+        }
+    }
+}
+
+/// Multiplies two little-endian multi-limb integers.
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = product[i + j] as u128 + x as u128 * y as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    product
+}
+
+/// Shifts a little-endian multi-limb integer right by `shift` bits.
+fn shr_limbs(limbs: &[u64], shift: usize) -> Vec<u64> {
+    let (words, bits) = (shift / 64, shift % 64);
+    (words..limbs.len())
+        .map(|i| {
+            let high = match (bits, limbs.get(i + 1)) {
+                (0, _) | (_, None) => 0,
+                (_, Some(next)) => next << (64 - bits),
+            };
+            (limbs[i] >> bits) | high
+        })
+        .collect()
+}
+
+/// Returns a fixed quadratic non-residue of the field.
+//
+// Every coefficient of the candidates is non-zero, so they do not lie in a subfield of even
+// index, all of whose elements would be squares.
+fn non_square<F: ArkField>() -> F {
+    let degree = F::extension_degree() as usize;
+    (2u64..)
+        .filter_map(|k| {
+            let elems = (0..degree).map(|i| match i {
+                0 => F::BasePrimeField::from(k),
+                _ => F::BasePrimeField::ONE,
+            });
+            F::from_base_prime_field_elems(elems)
+        })
+        .find(|candidate| candidate.legendre() == LegendreSymbol::QuadraticNonResidue)
+        // Note: This unwrap is safe because half of the non-zero elements are non-residues
+        .unwrap()
+}
+
+fn tonelli_shanks<F: ArkField>(a: &F) -> Option<F> {
+    match a.legendre() {
+        LegendreSymbol::Zero => return Some(F::ZERO),
+        LegendreSymbol::QuadraticNonResidue => return None,
+        LegendreSymbol::QuadraticResidue => {}
+    }
+
+    // Write the order of the multiplicative group as 2^s * t with t odd
+    let characteristic = F::characteristic();
+    let mut order = vec![1u64];
+    for _ in 0..F::extension_degree() {
+        order = mul_limbs(&order, characteristic);
+    }
+    // The order of the field is odd, so subtracting one never borrows
+    order[0] -= 1;
+    let s = order
+        .iter()
+        .enumerate()
+        .find(|(_, limb)| **limb != 0)
+        .map(|(i, limb)| i * 64 + limb.trailing_zeros() as usize)
+        // Note: This unwrap is safe because the order of the multiplicative group is non-zero
+        .unwrap();
+    let t = shr_limbs(&order, s);
+
+    let mut m = s;
+    let mut c = non_square::<F>().pow(&t);
+    let w = a.pow(shr_limbs(&t, 1));
+    let mut x = *a * w;
+    let mut b = x * w;
+    while !b.is_one() {
+        let mut i = 0;
+        let mut b2 = b;
+        while !b2.is_one() {
+            b2.square_in_place();
+            i += 1;
+        }
+        for _ in 0..m - i - 1 {
+            c.square_in_place();
+        }
+        x *= c;
+        c.square_in_place();
+        b *= c;
+        m = i;
+    }
+    Some(x)
+}
+
+impl<F: ArkField> ConditionallySelectable for ArkExtFieldWrapper<F> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        if choice.into() { *b } else { *a }
+    }
+}
+
+impl<F: ArkField> ConstantTimeEq for ArkExtFieldWrapper<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from(u8::from(self.0 == other.0))
+    }
+}
+
+impl<F: ArkField> ff::Field for ArkExtFieldWrapper<F> {
+    const ZERO: Self = Self(F::ZERO);
+    const ONE: Self = Self(F::ONE);
+
+    fn random(mut rng: impl rand_core::RngCore) -> Self {
+        Self::new(F::rand(&mut rng))
+    }
+
+    fn square(&self) -> Self {
+        Self::new(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        Self::new(self.0.double())
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        ArkExtFieldWrapper::invert(self)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        ArkExtFieldWrapper::sqrt(self)
+    }
+
+    /// Follows the `ff` contract: when `num / div` is not a square, returns the square root of
+    /// `num / div` times a fixed non-residue of the field.
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        let Some(div_inverse) = div.0.inverse() else {
+            return (Choice::from(u8::from(num.0.is_zero())), Self::ZERO);
+        };
+        let ratio = num.0 * div_inverse;
+        match tonelli_shanks(&ratio) {
+            Some(root) => (Choice::from(1), Self::new(root)),
+            None => {
+                // Note: This unwrap is safe because the product of two non-residues is a residue
+                let root = tonelli_shanks(&(ratio * non_square::<F>())).unwrap();
+                (Choice::from(0), Self::new(root))
+            }
+        }
+    }
+}
+
+impl<F: ArkField> Add for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<F: ArkField> Add<&Self> for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<F: ArkField> AddAssign for ArkExtFieldWrapper<F> {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<F: ArkField> AddAssign<&Self> for ArkExtFieldWrapper<F> {
+    fn add_assign(&mut self, other: &Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<F: ArkField> Sub for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<F: ArkField> Sub<&Self> for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn sub(self, other: &Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<F: ArkField> SubAssign for ArkExtFieldWrapper<F> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<F: ArkField> SubAssign<&Self> for ArkExtFieldWrapper<F> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<F: ArkField> Mul for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.0 * other.0)
+    }
+}
+
+impl<F: ArkField> Mul<&Self> for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn mul(self, other: &Self) -> Self {
+        Self::new(self.0 * other.0)
+    }
+}
+
+impl<F: ArkField> MulAssign for ArkExtFieldWrapper<F> {
+    fn mul_assign(&mut self, other: Self) {
+        self.0 *= other.0;
+    }
+}
+
+impl<F: ArkField> MulAssign<&Self> for ArkExtFieldWrapper<F> {
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 *= other.0;
+    }
+}
+
+impl<F: ArkField> Neg for ArkExtFieldWrapper<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+impl<F: ArkField> core::iter::Sum for ArkExtFieldWrapper<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: ArkField> core::iter::Sum<&'a Self> for ArkExtFieldWrapper<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<F: ArkField> core::iter::Product for ArkExtFieldWrapper<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ONE), |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: ArkField> core::iter::Product<&'a Self> for ArkExtFieldWrapper<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ONE), |acc, x| acc * x)
+    }
+}
+
+impl<F: ArkField> CanonicalSerialize for ArkExtFieldWrapper<F> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.serialized_size(compress)
+    }
+}
+
+impl<F: ArkField> Valid for ArkExtFieldWrapper<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl<F: ArkField> CanonicalDeserialize for ArkExtFieldWrapper<F> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        F::deserialize_with_mode(reader, compress, validate).map(Self::new)
+    }
+}
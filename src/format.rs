@@ -0,0 +1,166 @@
+//! Hex formatting and parsing based on the canonical encodings.
+//!
+//! `Display` and `LowerHex` print the same bytes as `GroupEncoding::to_bytes` and
+//! `PrimeField::to_repr`, so log lines and test fixtures match what goes on the wire. The
+//! alternate flag (`{:#x}`) adds a `0x` prefix, which the parsers accept as well.
+
+use crate::group::{PointRepr, UncompressedPointRepr};
+use crate::scalar::ScalarRepr;
+use crate::{ArkGroupWrapper, ArkScalarWrapper};
+use ark_ff::PrimeField as ArkPrimeField;
+use ark_serialize::CanonicalSerialize;
+use core::fmt;
+use core::str::FromStr;
+use elliptic_curve::group::GroupEncoding;
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], upper: bool) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    for byte in bytes {
+        if upper {
+            write!(f, "{byte:02X}")?;
+        } else {
+            write!(f, "{byte:02x}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Decodes `s`, with or without a `0x` prefix, into exactly `out.len()` bytes.
+fn read_hex(s: &str, out: &mut [u8]) -> elliptic_curve::Result<()> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    hex::decode_to_slice(s, out).map_err(|_| elliptic_curve::Error)
+}
+
+macro_rules! impl_hex_formatting {
+    ([$($generics:tt)*] $ty:ty, [$($bounds:tt)*], |$this:ident| $bytes:expr) => {
+        impl<$($generics)*> fmt::Display for $ty where $($bounds)* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl<$($generics)*> fmt::LowerHex for $ty where $($bounds)* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let $this = self;
+                write_hex(f, $bytes.as_ref(), false)
+            }
+        }
+
+        impl<$($generics)*> fmt::UpperHex for $ty where $($bounds)* {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let $this = self;
+                write_hex(f, $bytes.as_ref(), true)
+            }
+        }
+    };
+}
+
+impl_hex_formatting!([C] ArkGroupWrapper<C>, [Self: GroupEncoding], |point| point.to_bytes());
+impl_hex_formatting!(
+    [F: ArkPrimeField] ArkScalarWrapper<F>,
+    [Self: ff::PrimeField<Repr = ScalarRepr<F>>],
+    |scalar| ff::PrimeField::to_repr(scalar)
+);
+impl_hex_formatting!([const N: usize] PointRepr<N>, [], |repr| repr);
+impl_hex_formatting!([const N: usize] UncompressedPointRepr<N>, [], |repr| repr);
+impl_hex_formatting!([F: ArkPrimeField] ScalarRepr<F>, [], |repr| repr);
+
+impl<const N: usize> fmt::Debug for PointRepr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PointRepr({self:#x})")
+    }
+}
+
+impl<const N: usize> fmt::Debug for UncompressedPointRepr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UncompressedPointRepr({self:#x})")
+    }
+}
+
+impl<F: ArkPrimeField> fmt::Debug for ScalarRepr<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ScalarRepr({self:#x})")
+    }
+}
+
+/// Streams bytes written through `ark_serialize::Write` to a formatter as lowercase hex.
+struct HexWriter<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl ark_serialize::Write for HexWriter<'_, '_> {
+    fn write(&mut self, bytes: &[u8]) -> ark_std::io::Result<usize> {
+        for byte in bytes {
+            write!(self.0, "{byte:02x}").map_err(|_| ark_std::io::ErrorKind::Other)?;
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> ark_std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// `Debug` prints the arkworks compressed serialisation, which needs no encoding constants, so
+// it is available for every curve. For all curves except Ed25519 these are the
+// `GroupEncoding` bytes.
+impl<C: CanonicalSerialize> fmt::Debug for ArkGroupWrapper<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ArkGroupWrapper(0x")?;
+        self.0
+            .serialize_compressed(HexWriter(f))
+            .map_err(|_| fmt::Error)?;
+        f.write_str(")")
+    }
+}
+
+impl<C> ArkGroupWrapper<C>
+where
+    Self: GroupEncoding,
+{
+    /// Parses the hex form of the compressed encoding, rejecting points that are not on the
+    /// curve or not in the prime-order subgroup.
+    pub fn from_hex(s: &str) -> elliptic_curve::Result<Self> {
+        let mut repr = <Self as GroupEncoding>::Repr::default();
+        read_hex(s, repr.as_mut())?;
+        Option::from(Self::from_bytes(&repr)).ok_or(elliptic_curve::Error)
+    }
+}
+
+impl<C> FromStr for ArkGroupWrapper<C>
+where
+    Self: GroupEncoding,
+{
+    type Err = elliptic_curve::Error;
+
+    fn from_str(s: &str) -> elliptic_curve::Result<Self> {
+        Self::from_hex(s)
+    }
+}
+
+impl<F> ArkScalarWrapper<F>
+where
+    F: ArkPrimeField,
+    Self: ff::PrimeField<Repr = ScalarRepr<F>>,
+{
+    /// Parses the hex form of `to_repr`, rejecting values that are not reduced modulo the
+    /// field characteristic.
+    pub fn from_hex(s: &str) -> elliptic_curve::Result<Self> {
+        use ff::PrimeField;
+        let mut repr = ScalarRepr::<F>::default();
+        read_hex(s, repr.as_mut())?;
+        Option::from(Self::from_repr(repr)).ok_or(elliptic_curve::Error)
+    }
+}
+
+impl<F> FromStr for ArkScalarWrapper<F>
+where
+    F: ArkPrimeField,
+    Self: ff::PrimeField<Repr = ScalarRepr<F>>,
+{
+    type Err = elliptic_curve::Error;
+
+    fn from_str(s: &str) -> elliptic_curve::Result<Self> {
+        Self::from_hex(s)
+    }
+}
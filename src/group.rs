@@ -1,5 +1,13 @@
-use ark_ec::CurveGroup;
-use subtle::{Choice, ConstantTimeEq};
+use crate::ArkScalarWrapper;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField as ArkPrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use elliptic_curve::Group;
+use elliptic_curve::group::{GroupEncoding, UncompressedEncoding};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[derive(Clone, Copy)]
 pub struct PointRepr<const N: usize>([u8; N]);
@@ -22,10 +30,40 @@ impl<const N: usize> AsMut<[u8]> for PointRepr<N> {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[derive(Clone, Copy)]
+pub struct UncompressedPointRepr<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for UncompressedPointRepr<N> {
+    fn default() -> Self {
+        Self([0u8; N])
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for UncompressedPointRepr<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for UncompressedPointRepr<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Wraps an arkworks curve point to implement the RustCrypto and zkcrypto group traits.
+///
+/// The `PrimeGroup` and `PrimeCurve` impls assume the point is in the prime-order subgroup. That
+/// holds for values built through the checked constructors (`from_bytes`, `from_uncompressed`,
+/// `from_coordinates`, the hash-to-curve functions, `Group::random` and `Group::generator`) and
+/// for arithmetic on such values. It does not hold in general for [`Self::new`], for
+/// `from_uncompressed_unchecked`, or for `CanonicalDeserialize` with `Validate::No`.
+#[derive(Default, Copy, Clone, Eq, PartialEq)]
 pub struct ArkGroupWrapper<G>(pub(crate) G);
 
 impl<C: CurveGroup> ArkGroupWrapper<C> {
+    /// Wraps `point` as is. Its subgroup membership is not checked, so the `PrimeGroup` guarantees
+    /// only hold if the caller knows the point is in the prime-order subgroup.
     pub fn new(point: C) -> Self {
         Self(point)
     }
@@ -45,238 +83,349 @@ impl<C: CurveGroup> ConstantTimeEq for ArkGroupWrapper<C> {
     }
 }
 
-#[macro_export]
-macro_rules! impl_group_for_curve {
-    ($curve:ty, $constants:ident) => {
-        impl elliptic_curve::Group for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup + 'static,
-        {
-            type Scalar = $crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>;
+// The arkworks serialisation traits delegate to the wrapped point, so wrappers can sit in
+// structures that derive them
+impl<G: CanonicalSerialize> CanonicalSerialize for ArkGroupWrapper<G> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0.serialize_with_mode(writer, compress)
+    }
 
-            fn random(mut rng: impl rand_core::RngCore) -> Self {
-                use ark_ff::UniformRand;
-                Self::new(<$curve>::rand(&mut rng))
-            }
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.serialized_size(compress)
+    }
+}
 
-            fn identity() -> Self {
-                use ark_ff::Zero;
-                Self::new(<$curve>::zero())
-            }
+impl<G: Valid> Valid for ArkGroupWrapper<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
 
-            fn generator() -> Self {
-                use ark_ec::PrimeGroup;
-                Self::new(<$curve>::generator())
-            }
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        G::batch_check(batch.map(|point| &point.0))
+    }
+}
 
-            fn is_identity(&self) -> subtle::Choice {
-                use ark_ff::Zero;
-                if self.0.is_zero() {
-                    subtle::Choice::from(1)
-                } else {
-                    subtle::Choice::from(0)
-                }
-            }
+impl<G: CanonicalDeserialize> CanonicalDeserialize for ArkGroupWrapper<G> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        G::deserialize_with_mode(reader, compress, validate).map(Self)
+    }
+}
 
-            fn double(&self) -> Self {
-                use ark_ec::AdditiveGroup;
-                Self::new(self.0.double())
-            }
-        }
+// The group traits are implemented once for every arkworks curve rather than per curve. Only the
+// encodings, whose sizes cannot be computed from associated constants on stable Rust, still need
+// generated constants.
+impl<C: CurveGroup> Group for ArkGroupWrapper<C>
+where
+    ArkScalarWrapper<C::ScalarField>: ff::PrimeField,
+{
+    type Scalar = ArkScalarWrapper<C::ScalarField>;
+
+    fn random(mut rng: impl rand_core::RngCore) -> Self {
+        Self::new(C::rand(&mut rng))
+    }
 
-        // Implement scalar multiplication
-        impl core::ops::Mul<crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>>
-            for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup + 'static,
-        {
-            type Output = Self;
-            fn mul(
-                self,
-                scalar: crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            ) -> Self {
-                Self::new(self.0.mul(scalar.into_inner()))
-            }
-        }
+    fn identity() -> Self {
+        Self::new(C::zero())
+    }
 
-        impl core::ops::Mul<&crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>>
-            for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup + 'static,
-        {
-            type Output = Self;
-            fn mul(
-                self,
-                scalar: &crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            ) -> Self {
-                Self::new(self.0.mul(scalar.into_inner()))
-            }
-        }
+    fn generator() -> Self {
+        Self::new(C::generator())
+    }
 
-        impl
-            core::ops::MulAssign<
-                crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            > for $crate::ArkGroupWrapper<$curve>
-        {
-            fn mul_assign(
-                &mut self,
-                scalar: crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            ) {
-                self.0.mul_assign(scalar.into_inner())
-            }
-        }
+    fn is_identity(&self) -> Choice {
+        Choice::from(u8::from(self.0.is_zero()))
+    }
 
-        impl
-            core::ops::MulAssign<
-                &crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            > for $crate::ArkGroupWrapper<$curve>
-        {
-            fn mul_assign(
-                &mut self,
-                scalar: &crate::ArkScalarWrapper<<$curve as ark_ec::PrimeGroup>::ScalarField>,
-            ) {
-                self.0.mul_assign(scalar.into_inner())
-            }
-        }
+    fn double(&self) -> Self {
+        Self::new(self.0.double())
+    }
+}
 
-        // Value-based arithmetic implementations
-        impl core::ops::Add for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            type Output = Self;
-            fn add(self, other: Self) -> Self {
-                Self::new(self.0 + other.0)
-            }
-        }
+// Scalar multiplication
+impl<C: CurveGroup> Mul<ArkScalarWrapper<C::ScalarField>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn mul(self, scalar: ArkScalarWrapper<C::ScalarField>) -> Self {
+        Self::new(self.0 * scalar.into_inner())
+    }
+}
 
-        impl core::ops::Sub for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            type Output = Self;
-            fn sub(self, other: Self) -> Self {
-                Self::new(self.0 - other.0)
-            }
-        }
+impl<C: CurveGroup> Mul<&ArkScalarWrapper<C::ScalarField>> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn mul(self, scalar: &ArkScalarWrapper<C::ScalarField>) -> Self {
+        Self::new(self.0 * scalar.into_inner())
+    }
+}
 
-        impl core::ops::Neg for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            type Output = Self;
-            fn neg(self) -> Self {
-                Self::new(-self.0)
-            }
-        }
+impl<C: CurveGroup> MulAssign<ArkScalarWrapper<C::ScalarField>> for ArkGroupWrapper<C> {
+    fn mul_assign(&mut self, scalar: ArkScalarWrapper<C::ScalarField>) {
+        self.0 *= scalar.into_inner();
+    }
+}
 
-        // Reference-based arithmetic implementations
-        impl core::ops::Add<&Self> for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            type Output = Self;
-            fn add(self, other: &Self) -> Self {
-                Self::new(self.0 + other.0)
-            }
-        }
+impl<C: CurveGroup> MulAssign<&ArkScalarWrapper<C::ScalarField>> for ArkGroupWrapper<C> {
+    fn mul_assign(&mut self, scalar: &ArkScalarWrapper<C::ScalarField>) {
+        self.0 *= scalar.into_inner();
+    }
+}
 
-        impl core::ops::Sub<&Self> for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            type Output = Self;
-            fn sub(self, other: &Self) -> Self {
-                Self::new(self.0 - other.0)
-            }
-        }
+impl<C: CurveGroup> Add for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
 
-        impl core::ops::AddAssign for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            fn add_assign(&mut self, other: Self) {
-                self.0.add_assign(other.0);
-            }
-        }
+impl<C: CurveGroup> Add<&Self> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
 
-        impl core::ops::SubAssign for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            fn sub_assign(&mut self, other: Self) {
-                self.0.sub_assign(other.0);
-            }
-        }
+impl<C: CurveGroup> AddAssign for ArkGroupWrapper<C> {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
 
-        impl core::ops::AddAssign<&Self> for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            fn add_assign(&mut self, other: &Self) {
-                self.0.add_assign(other.0);
-            }
+impl<C: CurveGroup> AddAssign<&Self> for ArkGroupWrapper<C> {
+    fn add_assign(&mut self, other: &Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<C: CurveGroup> Sub for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<C: CurveGroup> Sub<&Self> for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn sub(self, other: &Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<C: CurveGroup> SubAssign for ArkGroupWrapper<C> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<C: CurveGroup> SubAssign<&Self> for ArkGroupWrapper<C> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<C: CurveGroup> Neg for ArkGroupWrapper<C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+impl<C: CurveGroup> core::iter::Sum for ArkGroupWrapper<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(C::zero()), |acc, x| acc + x)
+    }
+}
+
+impl<'a, C: CurveGroup> core::iter::Sum<&'a Self> for ArkGroupWrapper<C> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(C::zero()), |acc, x| acc + x)
+    }
+}
+
+/// The point encodings of a curve, implemented on its arkworks curve config to give
+/// `ArkGroupWrapper<Projective<Config>>` `GroupEncoding` and `UncompressedEncoding` with the
+/// arkworks compressed and uncompressed serialisations.
+///
+/// The reprs must be exactly as long as those serialisations, as written to
+/// `COMPRESSED_POINT_SIZE` and `UNCOMPRESSED_POINT_SIZE` by
+/// [`codegen::generate_curve_constants`](crate::codegen::generate_curve_constants).
+/// [`wrap_curve!`](crate::wrap_curve) implements this trait from such a constants module. The
+/// trait is implemented on the config, so crates that define their own curve can implement it:
+///
+/// ```
+/// use ark_ec::CurveConfig;
+/// use ark_ec::short_weierstrass::{self, SWCurveConfig};
+/// use ark_elliptic_curve_groups::ArkGroupWrapper;
+/// use elliptic_curve::group::prime::PrimeCurve;
+/// use elliptic_curve::group::{Group, GroupEncoding};
+///
+/// // A curve config defined outside this crate, reusing secp256k1's parameters
+/// pub struct MyConfig;
+///
+/// impl CurveConfig for MyConfig {
+///     type BaseField = ark_secp256k1::Fq;
+///     type ScalarField = ark_secp256k1::Fr;
+///     const COFACTOR: &'static [u64] = &[1];
+///     const COFACTOR_INV: Self::ScalarField = ark_secp256k1::Config::COFACTOR_INV;
+/// }
+///
+/// impl SWCurveConfig for MyConfig {
+///     const COEFF_A: Self::BaseField = ark_secp256k1::Config::COEFF_A;
+///     const COEFF_B: Self::BaseField = ark_secp256k1::Config::COEFF_B;
+///     const GENERATOR: short_weierstrass::Affine<Self> = short_weierstrass::Affine::new_unchecked(
+///         ark_secp256k1::Config::GENERATOR.x,
+///         ark_secp256k1::Config::GENERATOR.y,
+///     );
+/// }
+///
+/// // As written by `codegen::generate_curve_constants::<short_weierstrass::Projective<MyConfig>>`
+/// mod my_curve_constants {
+///     pub const COMPRESSED_POINT_SIZE: usize = 33;
+///     pub const UNCOMPRESSED_POINT_SIZE: usize = 65;
+/// }
+///
+/// ark_elliptic_curve_groups::wrap_curve!(MyConfig, my_curve_constants);
+///
+/// fn round_trip<G: PrimeCurve>() {
+///     let point = G::random(rand_core::OsRng);
+///     assert_eq!(G::from_bytes(&point.to_bytes()).unwrap(), point);
+/// }
+///
+/// round_trip::<ArkGroupWrapper<short_weierstrass::Projective<MyConfig>>>();
+/// ```
+pub trait ArkEncoding {
+    type Repr: Copy + Default + Send + Sync + 'static + AsRef<[u8]> + AsMut<[u8]>;
+    type UncompressedRepr: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Whether `GroupEncoding::from_bytes` replaces the arkworks subgroup check with a
+    /// double-and-add by the group order. Only set this for curves whose arkworks check
+    /// allocates, such as BLS12-381 G1; the arkworks checks are otherwise much cheaper.
+    const ALLOCATING_SUBGROUP_CHECK: bool = false;
+}
+
+/// Checks that a repr is exactly as long as the arkworks serialisation of the curve's points. A
+/// `wrap_curve!` constants module for another curve would otherwise give a repr that is
+/// zero-padded, or too short for any point to encode or decode.
+fn debug_assert_repr_len<C: CurveGroup>(len: usize, compress: Compress) {
+    debug_assert_eq!(
+        len,
+        C::zero().serialized_size(compress),
+        "the `ArkEncoding` repr length does not match the serialised point size"
+    );
+}
+
+pub(crate) fn from_compressed_bytes<C: CurveGroup>(
+    bytes: &[u8],
+    allocating_subgroup_check: bool,
+) -> CtOption<ArkGroupWrapper<C>> {
+    debug_assert_repr_len::<C>(bytes.len(), Compress::Yes);
+    // Note: This is not constant-time, but arkworks doesn't provide constant-time deserialization
+    let point = if allocating_subgroup_check {
+        C::deserialize_with_mode(bytes, Compress::Yes, Validate::No)
+            .ok()
+            .filter(|point| {
+                point
+                    .into_affine()
+                    .mul_bigint(C::ScalarField::MODULUS)
+                    .is_zero()
+            })
+    } else {
+        C::deserialize_with_mode(bytes, Compress::Yes, Validate::Yes).ok()
+    };
+    match point {
+        Some(point) => CtOption::new(ArkGroupWrapper::new(point), Choice::from(1)),
+        None => CtOption::new(ArkGroupWrapper::new(C::zero()), Choice::from(0)),
+    }
+}
+
+pub(crate) fn from_uncompressed_bytes<C: CurveGroup>(
+    bytes: &[u8],
+    validate: Validate,
+    is_on_curve: impl FnOnce(&C::Affine) -> bool,
+) -> CtOption<ArkGroupWrapper<C>> {
+    debug_assert_repr_len::<C>(bytes.len(), Compress::No);
+    // Note: This is not constant-time, but arkworks doesn't provide constant-time deserialization
+    // `Validate::No` skips the subgroup check, which `UncompressedEncoding` allows for the
+    // unchecked decoder, but arkworks skips the on-curve check with it as well. That check is
+    // always done here, so an unchecked decode never yields a point on another curve.
+    match C::deserialize_with_mode(bytes, Compress::No, validate) {
+        Ok(point) => {
+            let on_curve = Choice::from(is_on_curve(&point.into_affine()) as u8);
+            CtOption::new(ArkGroupWrapper::new(point), on_curve)
         }
+        Err(_) => CtOption::new(ArkGroupWrapper::new(C::zero()), Choice::from(0)),
+    }
+}
 
-        impl core::ops::SubAssign<&Self> for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
+pub(crate) fn to_repr<C: CurveGroup, R: Default + AsMut<[u8]>>(point: &C, compress: Compress) -> R {
+    let mut repr = R::default();
+    debug_assert_repr_len::<C>(repr.as_mut().len(), compress);
+    // Note: This unwrap is safe because `ArkEncoding` reprs are exactly as long as the serialisation
+    point.serialize_with_mode(repr.as_mut(), compress).unwrap();
+    repr
+}
+
+/// Implements `GroupEncoding` and `UncompressedEncoding` for a curve model whose configs
+/// implement [`ArkEncoding`].
+macro_rules! impl_encodings_for_model {
+    ($model:ident, $config:ident) => {
+        impl<P: ark_ec::$model::$config + ArkEncoding> GroupEncoding
+            for ArkGroupWrapper<ark_ec::$model::Projective<P>>
         {
-            fn sub_assign(&mut self, other: &Self) {
-                self.0.sub_assign(other.0);
+            type Repr = P::Repr;
+
+            fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                from_compressed_bytes(bytes.as_ref(), P::ALLOCATING_SUBGROUP_CHECK)
             }
-        }
 
-        // Implement Sum trait for iterator operations
-        impl core::iter::Sum for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
-        {
-            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-                use elliptic_curve::Group;
-                iter.fold(Self::identity(), |acc, x| acc + x)
+            fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+                // For arkworks curves, unchecked is the same as checked since validation
+                // is part of the deserialization process
+                Self::from_bytes(bytes)
             }
-        }
 
-        impl<'a> core::iter::Sum<&'a Self> for $crate::ArkGroupWrapper<$curve> {
-            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-                use elliptic_curve::Group;
-                iter.fold(Self::identity(), |acc, x| acc + x)
+            fn to_bytes(&self) -> Self::Repr {
+                to_repr(&self.0, Compress::Yes)
             }
         }
 
-        impl elliptic_curve::group::GroupEncoding for $crate::ArkGroupWrapper<$curve>
-        where
-            $curve: ark_ec::CurveGroup,
+        impl<P: ark_ec::$model::$config + ArkEncoding> UncompressedEncoding
+            for ArkGroupWrapper<ark_ec::$model::Projective<P>>
         {
-            type Repr = $crate::group::PointRepr<{ $constants::COMPRESSED_POINT_SIZE }>;
-
-            fn from_bytes(bytes: &Self::Repr) -> subtle::CtOption<Self> {
-                use ark_ff::Zero;
-                use ark_serialize::CanonicalDeserialize;
-                // Note: This is not constant-time, but arkworks doesn't provide constant-time deserialization
-                match <$curve>::deserialize_compressed(bytes.as_ref()) {
-                    Ok(point) => subtle::CtOption::new(Self::new(point), 1u8.into()),
-                    Err(_) => subtle::CtOption::new(Self::new(<$curve>::zero()), 0u8.into()),
-                }
+            type Uncompressed = P::UncompressedRepr;
+
+            fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+                from_uncompressed_bytes(
+                    bytes.as_ref(),
+                    Validate::Yes,
+                    ark_ec::$model::Affine::<P>::is_on_curve,
+                )
             }
 
-            fn from_bytes_unchecked(bytes: &Self::Repr) -> subtle::CtOption<Self> {
-                // For arkworks curves, unchecked is the same as checked since validation
-                // is part of the deserialization process
-                Self::from_bytes(bytes)
+            fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+                from_uncompressed_bytes(
+                    bytes.as_ref(),
+                    Validate::No,
+                    ark_ec::$model::Affine::<P>::is_on_curve,
+                )
             }
 
-            fn to_bytes(&self) -> Self::Repr {
-                use ark_serialize::CanonicalSerialize;
-                let mut buf = Vec::with_capacity($constants::COMPRESSED_POINT_SIZE);
-                // Note: This unwrap is safe because serialization to a Vec cannot fail
-                self.0.serialize_compressed(&mut buf).unwrap();
-                // Convert Vec<u8> to fixed-size array
-                let mut repr = Self::Repr::default();
-                repr.as_mut().copy_from_slice(&buf);
-                repr
+            fn to_uncompressed(&self) -> Self::Uncompressed {
+                to_repr(&self.0, Compress::No)
             }
         }
     };
 }
+
+impl_encodings_for_model!(short_weierstrass, SWCurveConfig);
+impl_encodings_for_model!(twisted_edwards, TECurveConfig);
@@ -0,0 +1,358 @@
+//! RFC 9380 hashing to curve points and scalars.
+//!
+//! `elliptic_curve::hash2curve::GroupDigest` can only be implemented for a curve with
+//! `CurveArithmetic`, whose scalars must use `FieldBytes` as their repr, so the suites are exposed
+//! as inherent `hash_to_curve`/`encode_to_curve` functions on the wrappers instead. The base field
+//! wrappers still implement `elliptic_curve::hash2curve::MapToCurve`, so generic RustCrypto code
+//! can combine them with `hash_to_field` and `CofactorGroup::clear_cofactor`. The BLS12-381
+//! suites use the arkworks maps; arkworks has no maps for the secp256k1, P-256 and edwards25519
+//! suites, so those follow the RFC directly. All of them interoperate with any other RFC 9380
+//! implementation of the same suite.
+
+use crate::{ArkExtFieldWrapper, ArkGroupWrapper, ArkScalarWrapper};
+use ark_ec::hashing::curve_maps::wb::WBMap;
+use ark_ec::hashing::map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher};
+use ark_ec::hashing::{HashToCurve, HashToCurveError};
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup, twisted_edwards};
+use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::{BigInteger, Field, MontFp, PrimeField};
+use core::marker::PhantomData;
+use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, FromOkm};
+use sha2::{Sha256, Sha512};
+
+/// The `_RO_` (random oracle) encoding: hashes to two field elements and adds their images.
+pub(crate) fn hash_to_curve<T, H2F, M2C>(msg: &[u8], dst: &[u8]) -> T
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    // Note: This unwrap is safe because the maps only fail for curve parameters they don't support
+    MapToCurveBasedHasher::<T, H2F, M2C>::new(dst)
+        .and_then(|hasher| hasher.hash(msg))
+        .unwrap()
+        .into_group()
+}
+
+/// The `_NU_` (nonuniform) encoding: hashes to a single field element and maps it to the curve.
+pub(crate) fn encode_to_curve<T, H2F, M2C>(msg: &[u8], dst: &[u8]) -> T
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    let [u] = H2F::new(dst).hash_to_field::<1>(msg);
+    // Note: This unwrap is safe because the maps only fail for curve parameters they don't support
+    M2C::map_to_curve(u).unwrap().clear_cofactor().into_group()
+}
+
+/// `hash_to_field` from RFC 9380 over a prime field, using a RustCrypto expander such as
+/// `ExpandMsgXmd` and the `FromOkm` impls of the scalar wrapper.
+///
+/// `DefaultFieldHasher` pads `expand_message_xmd` to the field element length rather than to the
+/// hash block size, which only matches the RFC when the two coincide, as they do for the BLS12-381
+/// suites.
+struct ExpandMsgFieldHasher<X> {
+    dst: Vec<u8>,
+    _expander: PhantomData<X>,
+}
+
+impl<F, X> HashToField<F> for ExpandMsgFieldHasher<X>
+where
+    F: PrimeField,
+    ArkScalarWrapper<F>: FromOkm,
+    X: for<'a> ExpandMsg<'a>,
+{
+    fn new(dst: &[u8]) -> Self {
+        Self {
+            dst: dst.to_vec(),
+            _expander: PhantomData,
+        }
+    }
+
+    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N] {
+        let mut elements = [ArkScalarWrapper::new(F::ZERO); N];
+        // Note: This unwrap is safe because the suites here ask for at most two field elements,
+        // far below the output limit of `expand_message_xmd`
+        elliptic_curve::hash2curve::hash_to_field::<X, _>(&[msg], &[&self.dst], &mut elements)
+            .unwrap();
+        elements.map(ArkScalarWrapper::into_inner)
+    }
+}
+
+/// The `sgn0` function from RFC 9380, section 4.1, for prime fields.
+fn sgn0<F: PrimeField>(x: F) -> bool {
+    x.into_bigint().is_odd()
+}
+
+/// Evaluates the polynomial with the given coefficients, lowest degree first, at `x`.
+fn evaluate<F: Field>(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::ZERO, |acc, coefficient| acc * x + coefficient)
+}
+
+/// The simplified SWU map from RFC 9380, section 6.6.2, onto `y^2 = x^3 + a * x + b`.
+///
+/// Note: This is not constant-time, but arkworks doesn't provide constant-time square roots
+fn simplified_swu<F: PrimeField>(u: F, a: F, b: F, z: F) -> (F, F) {
+    let z_u2 = z * u.square();
+    let tv1 = (z_u2.square() + z_u2).inverse().unwrap_or(F::ZERO);
+    let x1 = if tv1.is_zero() {
+        b / (z * a)
+    } else {
+        -b / a * (F::ONE + tv1)
+    };
+    let x2 = z_u2 * x1;
+    let g = |x: F| (x.square() + a) * x + b;
+    let (x, y) = match g(x1).sqrt() {
+        Some(y1) => (x1, y1),
+        // Note: This unwrap is safe because g(x2) is square whenever g(x1) is not
+        None => (x2, g(x2).sqrt().unwrap()),
+    };
+    if sgn0(u) == sgn0(y) { (x, y) } else { (x, -y) }
+}
+
+/// The simplified SWU map onto the curve 3-isogenous to secp256k1, followed by the isogeny, as
+/// specified in RFC 9380, section 8.7 and appendix E.1.
+struct Secp256k1Map;
+
+impl MapToCurve<ark_secp256k1::Projective> for Secp256k1Map {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Ok(())
+    }
+
+    fn map_to_curve(u: ark_secp256k1::Fq) -> Result<ark_secp256k1::Affine, HashToCurveError> {
+        type Fq = ark_secp256k1::Fq;
+        const ISO_A: Fq = MontFp!(
+            "28734576633528757162648956269730739219262246272443394170905244663053633733939"
+        );
+        const ISO_B: Fq = MontFp!("1771");
+        const Z: Fq = MontFp!("-11");
+        const X_NUM: [Fq; 4] = [
+            MontFp!(
+                "64328938465175664124206102782604393251816658147578091133031991115504908150983"
+            ),
+            MontFp!("3540463234204664767867377763959255381561641196938647754971861192896365225345"),
+            MontFp!(
+                "37676595701789655284650173187508961899444205326770530105295841645151729341026"
+            ),
+            MontFp!(
+                "64328938465175664124206102782604393251816658147578091133031991115504908150924"
+            ),
+        ];
+        const X_DEN: [Fq; 3] = [
+            MontFp!(
+                "95592507323525948732419199626899895302164312317343489384240252208201861084315"
+            ),
+            MontFp!(
+                "107505182841474506714709588670204841388457878609653642868747406790547894725908"
+            ),
+            MontFp!("1"),
+        ];
+        const Y_NUM: [Fq; 4] = [
+            MontFp!(
+                "34308767181427020866243254817389009734302217678708315270950395261602617680444"
+            ),
+            MontFp!(
+                "90176424683627901097894375140309208301239340832535417794535213712559228940707"
+            ),
+            MontFp!(
+                "18838297850894827642325086593754480949722102663385265052647920822575864670513"
+            ),
+            MontFp!(
+                "21442979488391888041402034260868131083938886049192697044343997038501636050308"
+            ),
+        ];
+        const Y_DEN: [Fq; 4] = [
+            MontFp!(
+                "115792089237316195423570985008687907853269984665640564039457584007908834670907"
+            ),
+            MontFp!(
+                "55193343495945455350115628863323870199952967620749340073805588608787913909619"
+            ),
+            MontFp!(
+                "45465685024895564648493397996619354229416833248839900263663526177913007417199"
+            ),
+            MontFp!("1"),
+        ];
+
+        let (x, y) = simplified_swu(u, ISO_A, ISO_B, Z);
+        let x_den = evaluate(&X_DEN, x);
+        let y_den = evaluate(&Y_DEN, x);
+        // The isogeny sends the points of its kernel to the identity
+        Ok(match (x_den * y_den).inverse() {
+            Some(inverse) => Affine::new_unchecked(
+                evaluate(&X_NUM, x) * y_den * inverse,
+                y * evaluate(&Y_NUM, x) * x_den * inverse,
+            ),
+            None => Affine::identity(),
+        })
+    }
+}
+
+/// The simplified SWU map onto P-256, as specified in RFC 9380, section 8.2.
+struct P256Map;
+
+impl MapToCurve<ark_secp256r1::Projective> for P256Map {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Ok(())
+    }
+
+    fn map_to_curve(u: ark_secp256r1::Fq) -> Result<ark_secp256r1::Affine, HashToCurveError> {
+        let (x, y) = simplified_swu(
+            u,
+            ark_secp256r1::Config::COEFF_A,
+            ark_secp256r1::Config::COEFF_B,
+            MontFp!("-10"),
+        );
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
+
+/// The Elligator 2 map onto curve25519 from RFC 9380, section 6.7.1, followed by the rational map
+/// to edwards25519 from appendix D.
+///
+/// Note: This is not constant-time, but arkworks doesn't provide constant-time square roots
+struct Edwards25519Map;
+
+impl MapToCurve<ark_ed25519::EdwardsProjective> for Edwards25519Map {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Ok(())
+    }
+
+    fn map_to_curve(u: ark_ed25519::Fq) -> Result<ark_ed25519::EdwardsAffine, HashToCurveError> {
+        type Fq = ark_ed25519::Fq;
+        const J: Fq = MontFp!("486662");
+        const Z: Fq = MontFp!("2");
+        // The square root of -486664 with sgn0 equal to 0
+        const C1: Fq =
+            MontFp!("6853475219497561581579357271197624642482790079785650197046958215289687604742");
+
+        // Elligator 2 onto curve25519, whose K is 1
+        let x1 = match (Fq::ONE + Z * u.square()).inverse() {
+            Some(inverse) => -J * inverse,
+            None => -J,
+        };
+        let x2 = -x1 - J;
+        let g = |x: Fq| ((x + J) * x + Fq::ONE) * x;
+        let (s, t) = match g(x1).sqrt() {
+            Some(y1) => (x1, if sgn0(y1) { y1 } else { -y1 }),
+            // Note: This unwrap is safe because g(x2) is square whenever g(x1) is not
+            None => {
+                let y2 = g(x2).sqrt().unwrap();
+                (x2, if sgn0(y2) { -y2 } else { y2 })
+            }
+        };
+
+        // The rational map from curve25519 to edwards25519, whose exceptional points go to the
+        // identity
+        let denominator = t * (s + Fq::ONE);
+        Ok(match denominator.inverse() {
+            Some(inverse) => twisted_edwards::Affine::new_unchecked(
+                C1 * s * (s + Fq::ONE) * inverse,
+                (s - Fq::ONE) * t * inverse,
+            ),
+            None => twisted_edwards::Affine::zero(),
+        })
+    }
+}
+
+impl<F> ArkScalarWrapper<F>
+where
+    F: PrimeField,
+    Self: FromOkm,
+{
+    /// Hashes `msg` to a uniformly distributed scalar with RFC 9380 `hash_to_field`, using
+    /// `expand_message_xmd` with SHA-256 and the domain separation tag `dst`.
+    pub fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Self {
+        let mut scalar = [Self::new(F::ZERO)];
+        // Note: This unwrap is safe because a single scalar is far below the output limit of
+        // `expand_message_xmd`
+        elliptic_curve::hash2curve::hash_to_field::<ExpandMsgXmd<Sha256>, Self>(
+            &[msg],
+            &[dst],
+            &mut scalar,
+        )
+        .unwrap();
+        scalar[0]
+    }
+}
+
+macro_rules! impl_hash_to_curve {
+    ($curve:ty, $field:ty, $hasher:ty, $map:ty, $ro_suite:literal, $nu_suite:literal) => {
+        impl elliptic_curve::hash2curve::MapToCurve for $field {
+            type Output = ArkGroupWrapper<$curve>;
+
+            /// Maps the field element to the curve, without clearing the cofactor.
+            fn map_to_curve(&self) -> Self::Output {
+                // Note: This unwrap is safe because the maps only fail for curve parameters they
+                // don't support
+                let point = <$map as MapToCurve<$curve>>::map_to_curve(self.into_inner()).unwrap();
+                ArkGroupWrapper::new(point.into_group())
+            }
+        }
+
+        impl ArkGroupWrapper<$curve> {
+            /// The RFC 9380 suite implemented by [`Self::hash_to_curve`].
+            pub const HASH_TO_CURVE_SUITE: &'static str = $ro_suite;
+            /// The RFC 9380 suite implemented by [`Self::encode_to_curve`].
+            pub const ENCODE_TO_CURVE_SUITE: &'static str = $nu_suite;
+
+            /// Hashes `msg` to a point in the prime-order subgroup that is indistinguishable from
+            /// a random oracle, using the domain separation tag `dst`.
+            pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+                Self::new(hash_to_curve::<$curve, $hasher, $map>(msg, dst))
+            }
+
+            /// Encodes `msg` to a point in the prime-order subgroup. This is cheaper than
+            /// [`Self::hash_to_curve`], but the output distribution is not uniform.
+            pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+                Self::new(encode_to_curve::<$curve, $hasher, $map>(msg, dst))
+            }
+        }
+    };
+}
+
+impl_hash_to_curve!(
+    Projective<ark_bls12_381::g1::Config>,
+    ArkScalarWrapper<ark_bls12_381::Fq>,
+    DefaultFieldHasher<Sha256, 128>,
+    WBMap<ark_bls12_381::g1::Config>,
+    "BLS12381G1_XMD:SHA-256_SSWU_RO_",
+    "BLS12381G1_XMD:SHA-256_SSWU_NU_"
+);
+impl_hash_to_curve!(
+    Projective<ark_bls12_381::g2::Config>,
+    ArkExtFieldWrapper<ark_bls12_381::Fq2>,
+    DefaultFieldHasher<Sha256, 128>,
+    WBMap<ark_bls12_381::g2::Config>,
+    "BLS12381G2_XMD:SHA-256_SSWU_RO_",
+    "BLS12381G2_XMD:SHA-256_SSWU_NU_"
+);
+impl_hash_to_curve!(
+    ark_secp256k1::Projective,
+    ArkScalarWrapper<ark_secp256k1::Fq>,
+    ExpandMsgFieldHasher<ExpandMsgXmd<Sha256>>,
+    Secp256k1Map,
+    "secp256k1_XMD:SHA-256_SSWU_RO_",
+    "secp256k1_XMD:SHA-256_SSWU_NU_"
+);
+impl_hash_to_curve!(
+    ark_secp256r1::Projective,
+    ArkScalarWrapper<ark_secp256r1::Fq>,
+    ExpandMsgFieldHasher<ExpandMsgXmd<Sha256>>,
+    P256Map,
+    "P256_XMD:SHA-256_SSWU_RO_",
+    "P256_XMD:SHA-256_SSWU_NU_"
+);
+impl_hash_to_curve!(
+    ark_ed25519::EdwardsProjective,
+    ArkScalarWrapper<ark_ed25519::Fq>,
+    ExpandMsgFieldHasher<ExpandMsgXmd<Sha512>>,
+    Edwards25519Map,
+    "edwards25519_XMD:SHA-512_ELL2_RO_",
+    "edwards25519_XMD:SHA-512_ELL2_NU_"
+);
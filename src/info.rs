@@ -0,0 +1,196 @@
+//! Metadata about every curve of [`ArkCurveId`], for tooling and configuration validation.
+//!
+//! The point sizes, generator encodings and base field degrees come from the constants modules
+//! written by `build.rs`; the moduli, scalar size, cofactor and curve model are read from the
+//! arkworks associated constants at compile time.
+
+use crate::any::{AnyCurve, ArkCurveId, curves};
+use crate::constants::*;
+use crate::{ArkGroupWrapper, ArkScalarWrapper};
+use ark_ec::models::CurveConfig;
+use ark_ec::{CurveGroup, short_weierstrass, twisted_edwards};
+use ff::PrimeField;
+
+/// The curve model that a wrapped curve's points use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurveFamily {
+    /// `y^2 = x^3 + ax + b`
+    ShortWeierstrass,
+    /// `ax^2 + y^2 = 1 + dx^2y^2`
+    TwistedEdwards,
+    /// `by^2 = x^3 + ax^2 + x`. Arkworks only implements the group law for the other two
+    /// models, so no wrapped curve uses it.
+    Montgomery,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveInfo {
+    pub id: ArkCurveId,
+    pub name: &'static str,
+    pub family: CurveFamily,
+    /// The scalar field modulus, as in `ff::PrimeField::MODULUS`.
+    pub scalar_modulus: &'static str,
+    /// The characteristic of the base field, which is an extension of degree
+    /// `base_field_degree` of the prime field for curves such as BLS12-381 G2.
+    pub base_modulus: &'static str,
+    pub base_field_degree: u64,
+    /// `ff::PrimeField::NUM_BITS` of the scalar field.
+    pub num_bits: u32,
+    /// The cofactor as little-endian `u64` limbs, as in arkworks' `CurveConfig::COFACTOR`.
+    pub cofactor: &'static [u64],
+    pub compressed_size: usize,
+    pub uncompressed_size: usize,
+    /// The `GroupEncoding` bytes of the generator.
+    pub generator: &'static [u8],
+    /// The ASN.1 object identifier of the named curve in dotted form, as used in SEC1 and X9.62
+    /// `ECParameters`, for curves that have one. Edwards curves such as Ed25519 only have
+    /// signature algorithm identifiers (RFC 8410), so they have none.
+    pub oid: Option<&'static str>,
+    /// The RFC 9380 suites that `hash_to_curve` and `encode_to_curve` implement.
+    pub hash_to_curve_suites: &'static [&'static str],
+}
+
+/// The arkworks curve models, which give the curve family and the curve config.
+pub trait CurveModel: CurveGroup {
+    type Config: CurveConfig;
+    const FAMILY: CurveFamily;
+}
+
+impl<P: short_weierstrass::SWCurveConfig> CurveModel for short_weierstrass::Projective<P> {
+    type Config = P;
+    const FAMILY: CurveFamily = CurveFamily::ShortWeierstrass;
+}
+
+impl<P: twisted_edwards::TECurveConfig> CurveModel for twisted_edwards::Projective<P> {
+    type Config = P;
+    const FAMILY: CurveFamily = CurveFamily::TwistedEdwards;
+}
+
+type BasePrimeField<C> = <<C as CurveGroup>::BaseField as ark_ff::Field>::BasePrimeField;
+
+impl<C: CurveModel> ArkGroupWrapper<C>
+where
+    ArkScalarWrapper<C::ScalarField>: PrimeField,
+    ArkScalarWrapper<BasePrimeField<C>>: PrimeField,
+{
+    const fn curve_info(
+        id: ArkCurveId,
+        constants: (usize, usize, &'static [u8], u64),
+        oid: &[&'static str],
+        hash_to_curve_suites: &'static [&'static str],
+    ) -> CurveInfo {
+        let (compressed_size, uncompressed_size, generator, base_field_degree) = constants;
+        CurveInfo {
+            id,
+            name: id.name(),
+            family: C::FAMILY,
+            scalar_modulus: <ArkScalarWrapper<C::ScalarField> as PrimeField>::MODULUS,
+            base_modulus: <ArkScalarWrapper<BasePrimeField<C>> as PrimeField>::MODULUS,
+            base_field_degree,
+            num_bits: <ArkScalarWrapper<C::ScalarField> as PrimeField>::NUM_BITS,
+            cofactor: <C as CurveModel>::Config::COFACTOR,
+            compressed_size,
+            uncompressed_size,
+            generator,
+            oid: match oid {
+                [oid] => Some(oid),
+                _ => None,
+            },
+            hash_to_curve_suites,
+        }
+    }
+}
+
+impl<C> ArkGroupWrapper<C>
+where
+    Self: AnyCurve,
+{
+    pub fn info() -> &'static CurveInfo {
+        <Self as AnyCurve>::CURVE.info()
+    }
+}
+
+/// Iterates over the metadata of every supported curve, in tag order.
+pub fn all_curves() -> impl Iterator<Item = &'static CurveInfo> {
+    ArkCurveId::ALL.iter().map(|curve| curve.info())
+}
+
+macro_rules! curve_info {
+    // The suite names come from the `hash_to_curve` impls, so they cannot drift apart
+    (@suites $id:ident hash_to_curve) => {
+        &[
+            curves::$id::HASH_TO_CURVE_SUITE,
+            curves::$id::ENCODE_TO_CURVE_SUITE,
+        ]
+    };
+    (@suites $id:ident) => {
+        &[]
+    };
+    ($($id:ident => $constants:ident $(, oid: $oid:literal)? $(, suites: $suites:ident)?;)*) => {
+        #[allow(non_upper_case_globals)]
+        mod registry {
+            use super::*;
+            $(
+                pub(super) const $id: CurveInfo = curves::$id::curve_info(
+                    ArkCurveId::$id,
+                    (
+                        $constants::COMPRESSED_POINT_SIZE,
+                        $constants::UNCOMPRESSED_POINT_SIZE,
+                        $constants::GENERATOR_COMPRESSED,
+                        $constants::BASE_FIELD_DEGREE,
+                    ),
+                    &[$($oid)?],
+                    curve_info!(@suites $id $($suites)?),
+                );
+            )*
+        }
+
+        impl ArkCurveId {
+            pub const fn info(self) -> &'static CurveInfo {
+                match self {
+                    $(Self::$id => &registry::$id,)*
+                }
+            }
+        }
+    };
+}
+
+curve_info! {
+    Secp256k1 => secp256k1_projective_constants, oid: "1.3.132.0.10", suites: hash_to_curve;
+    Secp256r1 => secp256r1_projective_constants, oid: "1.2.840.10045.3.1.7", suites: hash_to_curve;
+    Secp384r1 => secp384r1_projective_constants, oid: "1.3.132.0.34";
+    Secq256k1 => secq256k1_projective_constants;
+    Ed25519 => ed25519_projective_constants, suites: hash_to_curve;
+    Curve25519 => curve25519_projective_constants;
+    Pallas => pallas_projective_constants;
+    Vesta => vesta_projective_constants;
+    Grumpkin => grumpkin_projective_constants;
+    Bls12_381G1 => bls12_381_g1_constants, suites: hash_to_curve;
+    Bls12_381G2 => bls12_381_g2_constants, suites: hash_to_curve;
+    Bls12_377G1 => bls12_377_g1_constants;
+    Bls12_377G2 => bls12_377_g2_constants;
+    Bn254G1 => bn254_g1_constants;
+    Bn254G2 => bn254_g2_constants;
+    Bw6_761G1 => bw6_761_g1_constants;
+    Bw6_761G2 => bw6_761_g2_constants;
+    Bw6_767G1 => bw6_767_g1_constants;
+    Bw6_767G2 => bw6_767_g2_constants;
+    Cp6_782G1 => cp6_782_g1_constants;
+    Cp6_782G2 => cp6_782_g2_constants;
+    Mnt4_298G1 => mnt4_298_g1_constants;
+    Mnt4_298G2 => mnt4_298_g2_constants;
+    Mnt6_298G1 => mnt6_298_g1_constants;
+    Mnt6_298G2 => mnt6_298_g2_constants;
+    Mnt4_753G1 => mnt4_753_g1_constants;
+    Mnt4_753G2 => mnt4_753_g2_constants;
+    Mnt6_753G1 => mnt6_753_g1_constants;
+    Mnt6_753G2 => mnt6_753_g2_constants;
+    EdOnBls12_377 => ed_on_bls12_377_constants;
+    EdOnBls12_381 => ed_on_bls12_381_constants;
+    Bandersnatch => bandersnatch_constants;
+    EdOnBn254 => ed_on_bn254_constants;
+    EdOnCp6_782 => ed_on_cp6_782_constants;
+    EdOnMnt4_298 => ed_on_mnt4_298_constants;
+    EdOnMnt4_753 => ed_on_mnt4_753_constants;
+}
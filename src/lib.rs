@@ -1,97 +1,201 @@
+pub mod affine;
+pub mod any;
+/// Generators for the constants modules that the wrapping macros read, as used by `build.rs`.
+pub mod codegen;
+pub mod ed25519;
+pub mod evm;
+pub mod ext_field;
+mod format;
 pub mod group;
+pub mod hash_to_curve;
+pub mod info;
+pub mod pairing;
+#[cfg(feature = "rust-crypto")]
+pub mod rust_crypto;
 pub mod scalar;
+pub mod sec1;
+#[cfg(feature = "zeroize")]
+pub mod secret;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 #[cfg(test)]
 mod tests;
 
+pub use affine::ArkAffineWrapper;
+pub use any::{AnyArkPoint, AnyArkScalar, ArkCurveId};
 use constants::*;
-pub use group::ArkGroupWrapper;
+use elliptic_curve::consts::{U48, U54, U64, U112};
+pub use ext_field::ArkExtFieldWrapper;
+pub use group::{ArkEncoding, ArkGroupWrapper};
+pub use info::{CurveInfo, all_curves};
+#[cfg(feature = "rust-crypto")]
+pub use rust_crypto::{RustCryptoAffine, RustCryptoPoint, RustCryptoScalar};
 pub use scalar::ArkScalarWrapper;
+use scalar::impl_from_okm;
+#[cfg(feature = "zeroize")]
+pub use secret::SecretScalar;
 
 pub mod constants {
-    include!(concat!(env!("OUT_DIR"), "/field_constants.rs"));
     include!(concat!(env!("OUT_DIR"), "/curve_constants.rs"));
 }
 
-// Create modules for each implementation
-macro_rules! impl_field {
-    ($field:ty, $field_constants:ident) => {
-        paste::paste! {
-            mod [<__private_impl_field_ $field_constants>] {
-                use super::*;
-                impl_wrapped_field!($field, $field_constants);
-                impl_prime_field!($field, $field_constants);
-            }
+/// Implements [`ArkEncoding`](group::ArkEncoding) for an arkworks curve config, from a constants
+/// module written by [`codegen::generate_curve_constants`], which gives the wrapped curve
+/// `GroupEncoding` and `UncompressedEncoding`. The group and field traits themselves are
+/// implemented for every arkworks curve and prime field without any setup; only the encodings
+/// need the point sizes, which cannot be computed from associated constants on stable Rust.
+///
+/// The constants module is looked up at the call site. Since the trait is implemented on the
+/// config, the macro also works in crates that define their own curve.
+///
+/// Curves whose arkworks subgroup check allocates pass `allocating_subgroup_check` to set
+/// [`ArkEncoding::ALLOCATING_SUBGROUP_CHECK`](group::ArkEncoding::ALLOCATING_SUBGROUP_CHECK).
+///
+/// ```ignore
+/// wrap_curve!(MyCurveConfig, my_curve_constants);
+/// ```
+#[macro_export]
+macro_rules! wrap_curve {
+    ($config:ty, $curve_constants:ident) => {
+        impl $crate::group::ArkEncoding for $config {
+            type Repr = $crate::group::PointRepr<{ $curve_constants::COMPRESSED_POINT_SIZE }>;
+            type UncompressedRepr =
+                $crate::group::UncompressedPointRepr<{ $curve_constants::UNCOMPRESSED_POINT_SIZE }>;
+        }
+    };
+    ($config:ty, $curve_constants:ident, allocating_subgroup_check) => {
+        impl $crate::group::ArkEncoding for $config {
+            type Repr = $crate::group::PointRepr<{ $curve_constants::COMPRESSED_POINT_SIZE }>;
+            type UncompressedRepr =
+                $crate::group::UncompressedPointRepr<{ $curve_constants::UNCOMPRESSED_POINT_SIZE }>;
+            const ALLOCATING_SUBGROUP_CHECK: bool = true;
         }
     };
 }
 
-macro_rules! impl_group {
-    ($curve:ty, $curve_constants:ident) => {
+macro_rules! impl_pairing {
+    ($pairing:ty, $g1_config:ty, $g2_config:ty, $gt_constants:ident) => {
         paste::paste! {
-            mod [<__private_impl_group_ $curve_constants>] {
+            mod [<__private_impl_pairing_ $gt_constants>] {
                 use super::*;
-                impl_group_for_curve!($curve, $curve_constants);
+                $crate::pairing::impl_pairing_engine!($pairing, $g1_config, $g2_config);
+                $crate::pairing::impl_gt_encoding_for_pairing!($pairing, $gt_constants);
             }
         }
     };
 }
 
-// Implement field traits once per scalar field type
-impl_field!(ark_bls12_381::Fr, bls12_381_fr_constants);
-// impl_field!(ark_secp256k1::Fr, secp256k1_constants);
-// impl_field!(ark_secp256r1::Fr, secp256r1_constants);
-// impl_field!(ark_secp384r1::Fr, secp384r1_constants);
-// impl_field!(ark_secq256k1::Fr, secq256k1_constants);
-// impl_field!(ark_curve25519::Fr, curve25519_constants);
-// impl_field!(ark_bw6_767::Fr, bw6_767_fr_constants);
-// impl_field!(ark_bls12_377::Fr, bls12_377_fr_constants);
-// impl_field!(ark_bn254::Fr, bn254_fr_constants);
-// impl_field!(ark_bw6_761::Fr, bw6_761_fr_constants);
+// Implement `hash2curve::FromOkm` for each field that curves hash to. The `ff` traits need no
+// per-field setup.
+impl_from_okm!(ark_bls12_381::Fr, U48);
+impl_from_okm!(ark_secp256k1::Fr, U48);
+impl_from_okm!(ark_secp256r1::Fr, U48);
+impl_from_okm!(ark_secp384r1::Fr, U64);
+impl_from_okm!(ark_ed25519::Fr, U48);
+impl_from_okm!(ark_bls12_377::Fr, U48);
+impl_from_okm!(ark_bn254::Fr, U48);
+impl_from_okm!(ark_bw6_761::Fr, U64);
+impl_from_okm!(ark_mnt4_298::Fr, U54);
+impl_from_okm!(ark_mnt6_298::Fr, U54);
+impl_from_okm!(ark_secp256k1::Fq, U48);
+impl_from_okm!(ark_secp256r1::Fq, U48);
+impl_from_okm!(ark_secp384r1::Fq, U64);
+impl_from_okm!(ark_ed25519::Fq, U48);
+impl_from_okm!(ark_bls12_381::Fq, U64);
+impl_from_okm!(ark_bn254::Fq, U48);
+impl_from_okm!(ark_bw6_761::Fq, U112);
+
+// Implement the encoding traits for each curve, through its config
+//
+// Ed25519 is not listed: it implements `GroupEncoding` with the RFC 8032 format instead, in the
+// `ed25519` module.
+// The BLS12-381 G1 subgroup check multiplies through GLV, which allocates
+wrap_curve!(
+    ark_bls12_381::g1::Config,
+    bls12_381_g1_constants,
+    allocating_subgroup_check
+);
+wrap_curve!(ark_bls12_381::g2::Config, bls12_381_g2_constants);
+wrap_curve!(ark_bn254::g1::Config, bn254_g1_constants);
+wrap_curve!(ark_bn254::g2::Config, bn254_g2_constants);
+wrap_curve!(ark_bls12_377::g1::Config, bls12_377_g1_constants);
+wrap_curve!(ark_bls12_377::g2::Config, bls12_377_g2_constants);
+wrap_curve!(ark_bw6_761::g1::Config, bw6_761_g1_constants);
+wrap_curve!(ark_bw6_761::g2::Config, bw6_761_g2_constants);
+wrap_curve!(ark_bw6_767::g1::Config, bw6_767_g1_constants);
+wrap_curve!(ark_bw6_767::g2::Config, bw6_767_g2_constants);
+wrap_curve!(ark_cp6_782::g1::Config, cp6_782_g1_constants);
+wrap_curve!(ark_cp6_782::g2::Config, cp6_782_g2_constants);
+wrap_curve!(ark_mnt4_298::g1::Config, mnt4_298_g1_constants);
+wrap_curve!(ark_mnt4_298::g2::Config, mnt4_298_g2_constants);
+wrap_curve!(ark_mnt6_298::g1::Config, mnt6_298_g1_constants);
+wrap_curve!(ark_mnt6_298::g2::Config, mnt6_298_g2_constants);
+wrap_curve!(ark_mnt4_753::g1::Config, mnt4_753_g1_constants);
+wrap_curve!(ark_mnt4_753::g2::Config, mnt4_753_g2_constants);
+wrap_curve!(ark_mnt6_753::g1::Config, mnt6_753_g1_constants);
+wrap_curve!(ark_mnt6_753::g2::Config, mnt6_753_g2_constants);
+wrap_curve!(ark_secp256k1::Config, secp256k1_projective_constants);
+wrap_curve!(ark_secp256r1::Config, secp256r1_projective_constants);
+wrap_curve!(ark_secp384r1::Config, secp384r1_projective_constants);
+wrap_curve!(ark_secq256k1::Config, secq256k1_projective_constants);
+wrap_curve!(ark_pallas::PallasConfig, pallas_projective_constants);
+wrap_curve!(ark_vesta::VestaConfig, vesta_projective_constants);
+wrap_curve!(ark_grumpkin::GrumpkinConfig, grumpkin_projective_constants);
+wrap_curve!(
+    ark_curve25519::Curve25519Config,
+    curve25519_projective_constants
+);
+wrap_curve!(
+    ark_ed_on_bls12_377::EdwardsConfig,
+    ed_on_bls12_377_constants
+);
+wrap_curve!(ark_ed_on_bls12_381::JubjubConfig, ed_on_bls12_381_constants);
+wrap_curve!(
+    ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig,
+    bandersnatch_constants
+);
+wrap_curve!(ark_ed_on_bn254::EdwardsConfig, ed_on_bn254_constants);
+// `ark_ed_on_bw6_761` re-exports this curve
+wrap_curve!(ark_ed_on_cp6_782::EdwardsConfig, ed_on_cp6_782_constants);
+wrap_curve!(ark_ed_on_mnt4_298::EdwardsConfig, ed_on_mnt4_298_constants);
+wrap_curve!(ark_ed_on_mnt4_753::EdwardsConfig, ed_on_mnt4_753_constants);
 
-// Implement group traits for each curve
-impl_group!(ark_bls12_381::G1Projective, bls12_381_g1_constants);
-// impl_group!(ark_secp256k1::Projective, secp256k1_projective_constants);
-// impl_group!(ark_secp256r1::Projective, secp256r1_projective_constants);
-// impl_group!(ark_secp384r1::Projective, secp384r1_projective_constants);
-// impl_group!(ark_secq256k1::Projective, secq256k1_projective_constants);
-// impl_group!(
-//     ark_curve25519::EdwardsProjective,
-//     curve25519_projective_constants
-// );
-// impl_group!(ark_ed25519::EdwardsProjective, ed25519_projective_constants);
-// impl_group!(ark_bw6_767::G1Projective, bw6_767_g1_constants);
-// impl_group!(ark_bw6_767::G2Projective, bw6_767_g2_constants);
-// impl_group!(ark_cp6_782::G1Projective, cp6_782_g1_constants);
-// impl_group!(ark_cp6_782::G2Projective, cp6_782_g2_constants);
-// impl_group!(ark_bls12_377::G1Projective, bls12_377_g1_constants);
-// impl_group!(ark_bls12_377::G2Projective, bls12_377_g2_constants);
-// impl_group!(ark_bls12_381::G2Projective, bls12_381_g2_constants);
-// impl_group!(ark_bn254::G1Projective, bn254_g1_constants);
-// impl_group!(ark_bn254::G2Projective, bn254_g2_constants);
-// impl_group!(ark_bw6_761::G1Projective, bw6_761_g1_constants);
-// impl_group!(ark_bw6_761::G2Projective, bw6_761_g2_constants);
-// impl_group!(
-//     ark_ed_on_bls12_377::EdwardsProjective,
-//     ed_on_bls12_377_constants
-// );
-// impl_group!(
-//     ark_ed_on_bls12_381::EdwardsProjective,
-//     ed_on_bls12_381_constants
-// );
-// impl_group!(
-//     ark_ed_on_bw6_761::EdwardsProjective,
-//     ed_on_bw6_761_constants
-// );
-// impl_group!(
-//     ark_ed_on_cp6_782::EdwardsProjective,
-//     ed_on_cp6_782_constants
-// );
-// impl_group!(
-//     ark_ed_on_mnt4_298::EdwardsProjective,
-//     ed_on_mnt4_298_constants
-// );
-// impl_group!(
-//     ark_ed_on_mnt4_753::EdwardsProjective,
-//     ed_on_mnt4_753_constants
-// );
+// Implement the zkcrypto pairing traits and the target group encoding for each
+// pairing-friendly curve
+impl_pairing!(
+    ark_bls12_381::Bls12_381,
+    ark_bls12_381::g1::Config,
+    ark_bls12_381::g2::Config,
+    bls12_381_gt_constants
+);
+impl_pairing!(
+    ark_bls12_377::Bls12_377,
+    ark_bls12_377::g1::Config,
+    ark_bls12_377::g2::Config,
+    bls12_377_gt_constants
+);
+impl_pairing!(
+    ark_bn254::Bn254,
+    ark_bn254::g1::Config,
+    ark_bn254::g2::Config,
+    bn254_gt_constants
+);
+impl_pairing!(
+    ark_bw6_761::BW6_761,
+    ark_bw6_761::g1::Config,
+    ark_bw6_761::g2::Config,
+    bw6_761_gt_constants
+);
+impl_pairing!(
+    ark_mnt4_298::MNT4_298,
+    ark_mnt4_298::g1::Config,
+    ark_mnt4_298::g2::Config,
+    mnt4_298_gt_constants
+);
+impl_pairing!(
+    ark_mnt6_298::MNT6_298,
+    ark_mnt6_298::g1::Config,
+    ark_mnt6_298::g2::Config,
+    mnt6_298_gt_constants
+);
@@ -0,0 +1,349 @@
+use crate::ArkScalarWrapper;
+use ark_ec::pairing::{MillerLoopOutput, Pairing, PairingOutput};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::{Choice, ConstantTimeEq};
+
+/// An element of the pairing target group, written additively like arkworks' [`PairingOutput`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ArkGtWrapper<P: Pairing>(pub(crate) PairingOutput<P>);
+
+impl<P: Pairing> ArkGtWrapper<P> {
+    pub fn new(element: PairingOutput<P>) -> Self {
+        Self(element)
+    }
+
+    pub fn into_inner(self) -> PairingOutput<P> {
+        self.0
+    }
+}
+
+impl<P: Pairing> Default for ArkGtWrapper<P> {
+    fn default() -> Self {
+        Self(PairingOutput::default())
+    }
+}
+
+impl<P: Pairing> ConstantTimeEq for ArkGtWrapper<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from(u8::from(self.0 == other.0))
+    }
+}
+
+impl<P: Pairing> elliptic_curve::Group for ArkGtWrapper<P>
+where
+    ArkScalarWrapper<P::ScalarField>: ff::PrimeField,
+{
+    type Scalar = ArkScalarWrapper<P::ScalarField>;
+
+    fn random(mut rng: impl rand_core::RngCore) -> Self {
+        use ark_ff::UniformRand;
+        Self::new(PairingOutput::rand(&mut rng))
+    }
+
+    fn identity() -> Self {
+        use ark_ff::Zero;
+        Self::new(PairingOutput::zero())
+    }
+
+    fn generator() -> Self {
+        use ark_ec::PrimeGroup;
+        Self::new(PairingOutput::generator())
+    }
+
+    fn is_identity(&self) -> Choice {
+        use ark_ff::Zero;
+        Choice::from(u8::from(self.0.is_zero()))
+    }
+
+    fn double(&self) -> Self {
+        use ark_ec::AdditiveGroup;
+        Self::new(self.0.double())
+    }
+}
+
+impl<P: Pairing> Mul<ArkScalarWrapper<P::ScalarField>> for ArkGtWrapper<P> {
+    type Output = Self;
+    fn mul(self, scalar: ArkScalarWrapper<P::ScalarField>) -> Self {
+        Self::new(self.0 * scalar.into_inner())
+    }
+}
+
+impl<P: Pairing> Mul<&ArkScalarWrapper<P::ScalarField>> for ArkGtWrapper<P> {
+    type Output = Self;
+    fn mul(self, scalar: &ArkScalarWrapper<P::ScalarField>) -> Self {
+        Self::new(self.0 * scalar.into_inner())
+    }
+}
+
+impl<P: Pairing> MulAssign<ArkScalarWrapper<P::ScalarField>> for ArkGtWrapper<P> {
+    fn mul_assign(&mut self, scalar: ArkScalarWrapper<P::ScalarField>) {
+        self.0 *= scalar.into_inner();
+    }
+}
+
+impl<P: Pairing> MulAssign<&ArkScalarWrapper<P::ScalarField>> for ArkGtWrapper<P> {
+    fn mul_assign(&mut self, scalar: &ArkScalarWrapper<P::ScalarField>) {
+        self.0 *= scalar.into_inner();
+    }
+}
+
+impl<P: Pairing> Add for ArkGtWrapper<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<P: Pairing> Add<&Self> for ArkGtWrapper<P> {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl<P: Pairing> Sub for ArkGtWrapper<P> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<P: Pairing> Sub<&Self> for ArkGtWrapper<P> {
+    type Output = Self;
+    fn sub(self, other: &Self) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl<P: Pairing> Neg for ArkGtWrapper<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+impl<P: Pairing> AddAssign for ArkGtWrapper<P> {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<P: Pairing> AddAssign<&Self> for ArkGtWrapper<P> {
+    fn add_assign(&mut self, other: &Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<P: Pairing> SubAssign for ArkGtWrapper<P> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<P: Pairing> SubAssign<&Self> for ArkGtWrapper<P> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<P: Pairing> core::iter::Sum for ArkGtWrapper<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, P: Pairing> core::iter::Sum<&'a Self> for ArkGtWrapper<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+/// Implements `GroupEncoding` for the target group of a pairing, using the arkworks compressed
+/// serialisation of the target field element.
+macro_rules! impl_gt_encoding_for_pairing {
+    ($pairing:ty, $constants:ident) => {
+        impl elliptic_curve::group::GroupEncoding for $crate::pairing::ArkGtWrapper<$pairing> {
+            type Repr = $crate::group::PointRepr<{ $constants::COMPRESSED_GT_SIZE }>;
+
+            fn from_bytes(bytes: &Self::Repr) -> subtle::CtOption<Self> {
+                use ark_ec::pairing::PairingOutput;
+                use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+                // Note: This is not constant-time, but arkworks doesn't provide constant-time deserialization
+                // Validation includes the check that the element lies in the order-r subgroup
+                match PairingOutput::<$pairing>::deserialize_with_mode(
+                    bytes.as_ref(),
+                    Compress::Yes,
+                    Validate::Yes,
+                ) {
+                    Ok(element) => subtle::CtOption::new(Self::new(element), 1u8.into()),
+                    Err(_) => subtle::CtOption::new(Self::default(), 0u8.into()),
+                }
+            }
+
+            fn from_bytes_unchecked(bytes: &Self::Repr) -> subtle::CtOption<Self> {
+                use ark_ec::pairing::PairingOutput;
+                use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+                // Skips the subgroup check, so only use this for trusted input
+                match PairingOutput::<$pairing>::deserialize_with_mode(
+                    bytes.as_ref(),
+                    Compress::Yes,
+                    Validate::No,
+                ) {
+                    Ok(element) => subtle::CtOption::new(Self::new(element), 1u8.into()),
+                    Err(_) => subtle::CtOption::new(Self::default(), 0u8.into()),
+                }
+            }
+
+            fn to_bytes(&self) -> Self::Repr {
+                use ark_serialize::CanonicalSerialize;
+                let mut repr = Self::Repr::default();
+                // Note: This unwrap is safe because the repr is exactly `COMPRESSED_GT_SIZE` bytes
+                self.0.serialize_compressed(repr.as_mut()).unwrap();
+                repr
+            }
+        }
+    };
+}
+
+pub(crate) use impl_gt_encoding_for_pairing;
+
+/// A zkcrypto `pairing::Engine` backed by an arkworks [`Pairing`].
+///
+/// `G1`/`G2` are the [`ArkGroupWrapper`](crate::ArkGroupWrapper)s of the pairing's groups, their
+/// affine types are [`ArkAffineWrapper`](crate::ArkAffineWrapper)s and `Gt` is [`ArkGtWrapper`].
+#[derive(Debug, Copy, Clone)]
+pub struct ArkPairingEngine<P>(PhantomData<P>);
+
+/// A G2 point prepared for the Miller loop.
+#[derive(Debug, Clone)]
+pub struct ArkG2Prepared<P: Pairing>(pub(crate) P::G2Prepared);
+
+/// The output of a (multi) Miller loop, before the final exponentiation.
+///
+/// Like the target group, it is written additively: adding two results multiplies the
+/// underlying target field elements.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ArkMillerLoopResult<P: Pairing>(pub(crate) MillerLoopOutput<P>);
+
+impl<P: Pairing> Default for ArkMillerLoopResult<P> {
+    fn default() -> Self {
+        use ark_ff::One;
+        Self(MillerLoopOutput(P::TargetField::one()))
+    }
+}
+
+impl<P: Pairing> ArkMillerLoopResult<P> {
+    fn product(&self, other: &Self) -> Self {
+        Self(MillerLoopOutput(self.0.0 * other.0.0))
+    }
+}
+
+impl<P: Pairing> Add for ArkMillerLoopResult<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.product(&other)
+    }
+}
+
+impl<P: Pairing> Add<&Self> for ArkMillerLoopResult<P> {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        self.product(other)
+    }
+}
+
+impl<P: Pairing> AddAssign for ArkMillerLoopResult<P> {
+    fn add_assign(&mut self, other: Self) {
+        *self = self.product(&other);
+    }
+}
+
+impl<P: Pairing> AddAssign<&Self> for ArkMillerLoopResult<P> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = self.product(other);
+    }
+}
+
+impl<P: Pairing> ::pairing::MillerLoopResult for ArkMillerLoopResult<P>
+where
+    ArkScalarWrapper<P::ScalarField>: ff::PrimeField,
+{
+    type Gt = ArkGtWrapper<P>;
+
+    fn final_exponentiation(&self) -> Self::Gt {
+        // Note: This unwrap is safe because Miller loop outputs, and products of them, are never zero
+        ArkGtWrapper::new(P::final_exponentiation(self.0).unwrap())
+    }
+}
+
+/// Implements `pairing::Engine`, `pairing::MultiMillerLoop` and `pairing::PairingCurveAffine`
+/// for a pairing whose groups are short Weierstrass curves with the given configs.
+macro_rules! impl_pairing_engine {
+    ($pairing:ty, $g1_config:ty, $g2_config:ty) => {
+        impl ::pairing::Engine for $crate::pairing::ArkPairingEngine<$pairing> {
+            type Fr = $crate::ArkScalarWrapper<<$pairing as ark_ec::pairing::Pairing>::ScalarField>;
+            type G1 = $crate::ArkGroupWrapper<ark_ec::short_weierstrass::Projective<$g1_config>>;
+            type G1Affine = $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g1_config>>;
+            type G2 = $crate::ArkGroupWrapper<ark_ec::short_weierstrass::Projective<$g2_config>>;
+            type G2Affine = $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g2_config>>;
+            type Gt = $crate::pairing::ArkGtWrapper<$pairing>;
+
+            fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt {
+                use ark_ec::pairing::Pairing;
+                $crate::pairing::ArkGtWrapper::new(<$pairing>::pairing(p.0, q.0))
+            }
+        }
+
+        impl ::pairing::PairingCurveAffine
+            for $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g1_config>>
+        {
+            type Pair = $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g2_config>>;
+            type PairingResult = $crate::pairing::ArkGtWrapper<$pairing>;
+
+            fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+                <$crate::pairing::ArkPairingEngine<$pairing> as ::pairing::Engine>::pairing(
+                    self, other,
+                )
+            }
+        }
+
+        impl ::pairing::PairingCurveAffine
+            for $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g2_config>>
+        {
+            type Pair = $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g1_config>>;
+            type PairingResult = $crate::pairing::ArkGtWrapper<$pairing>;
+
+            fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+                <$crate::pairing::ArkPairingEngine<$pairing> as ::pairing::Engine>::pairing(
+                    other, self,
+                )
+            }
+        }
+
+        impl From<$crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g2_config>>>
+            for $crate::pairing::ArkG2Prepared<$pairing>
+        {
+            fn from(
+                point: $crate::ArkAffineWrapper<ark_ec::short_weierstrass::Affine<$g2_config>>,
+            ) -> Self {
+                Self(point.0.into())
+            }
+        }
+
+        impl ::pairing::MultiMillerLoop for $crate::pairing::ArkPairingEngine<$pairing> {
+            type G2Prepared = $crate::pairing::ArkG2Prepared<$pairing>;
+            type Result = $crate::pairing::ArkMillerLoopResult<$pairing>;
+
+            fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+                use ark_ec::pairing::Pairing;
+                $crate::pairing::ArkMillerLoopResult(<$pairing>::multi_miller_loop(
+                    terms.iter().map(|(p, _)| p.0),
+                    terms.iter().map(|(_, q)| q.0.clone()),
+                ))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_pairing_engine;
@@ -0,0 +1,1084 @@
+//! The reverse adapters: RustCrypto fields and curves presented through the arkworks traits, so
+//! that arkworks code such as SNARK gadgets and `VariableBaseMSM` can consume them.
+//!
+//! [`RustCryptoScalar<F>`] implements `ark_ff::PrimeField` for any `ff::PrimeField`, and
+//! [`RustCryptoPoint<G>`] implements `ark_ec::CurveGroup` for any [`RustCryptoCurve`], which the
+//! `k256` feature implements for `k256::ProjectivePoint`. The `N` parameter is the number of
+//! `u64` limbs of the arkworks `BigInt`s, and must be large enough for both moduli.
+//!
+//! Points use the arkworks short Weierstrass serialisation, so a serialised
+//! `RustCryptoPoint<k256::ProjectivePoint>` reads back as an `ark_secp256k1::Projective`.
+
+use ark_ec::short_weierstrass::SWFlags;
+use ark_ec::{AffineRepr, CurveConfig, CurveGroup, PrimeGroup, ScalarMul, VariableBaseMSM};
+use ark_ff::{
+    AdditiveGroup, BigInt, BigInteger, FftField, Field, LegendreSymbol, One,
+    PrimeField as ArkPrimeField, SqrtPrecomputation, Zero,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
+    buffer_byte_size,
+};
+use ark_std::rand::Rng;
+use ark_std::rand::distributions::{Distribution, Standard};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use elliptic_curve::group::prime::{PrimeCurve, PrimeCurveAffine};
+use elliptic_curve::group::{Group, GroupEncoding};
+use ff::PrimeField;
+use num_bigint::BigUint;
+
+/// Parses a `ff::PrimeField::MODULUS` hex string into `N` limbs.
+///
+/// `ff` leaves the format of `MODULUS` to the implementation. The string must either carry a `0x`
+/// prefix, as the zkcrypto fields write it, or be exactly `N` limbs of unprefixed hex, as the
+/// RustCrypto fields write it with `from_be_hex`; anything else, such as a decimal modulus, fails
+/// const evaluation rather than being misread as hex.
+const fn modulus_from_hex<const N: usize>(hex: &str) -> BigInt<N> {
+    let bytes = hex.as_bytes();
+    let start = if bytes.len() > 2 && bytes[0] == b'0' && bytes[1] == b'x' {
+        2
+    } else {
+        assert!(
+            bytes.len() == N * 16,
+            "`ff::PrimeField::MODULUS` is neither `0x`-prefixed nor `N` limbs of hex"
+        );
+        0
+    };
+    let mut limbs = [0u64; N];
+    let mut i = bytes.len();
+    let mut bit = 0;
+    while i > start {
+        i -= 1;
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => panic!("`ff::PrimeField::MODULUS` is not a hex string"),
+        } as u64;
+        if digit != 0 {
+            assert!(bit / 64 < N, "the modulus does not fit in `N` limbs");
+            limbs[bit / 64] |= digit << (bit % 64);
+        }
+        bit += 4;
+    }
+    BigInt::new(limbs)
+}
+
+/// Wraps an element of any `ff::PrimeField` to implement `ark_ff::PrimeField`.
+///
+/// RustCrypto fields may reduce lazily (`k256::FieldElement` tracks a magnitude), so sums,
+/// differences and negations are brought back to their canonical form through `to_repr`, and
+/// comparisons and hashing use the canonical encoding.
+#[derive(Clone, Copy, Debug)]
+pub struct RustCryptoScalar<F: PrimeField, const N: usize = 4> {
+    pub inner: F,
+}
+
+impl<F: PrimeField, const N: usize> RustCryptoScalar<F, N> {
+    pub const fn new(scalar: F) -> Self {
+        Self { inner: scalar }
+    }
+
+    pub const fn into_inner(self) -> F {
+        self.inner
+    }
+
+    fn reduced(scalar: F) -> Self {
+        // Note: This unwrap is safe because `to_repr` always returns a canonical encoding
+        Self::new(F::from_repr(scalar.to_repr()).unwrap())
+    }
+
+    /// The `ff` crate leaves the byte order of `Repr` to each field, so it is read from `ONE`.
+    fn repr_is_little_endian() -> bool {
+        F::ONE.to_repr().as_ref()[0] == 1
+    }
+
+    /// Reduces an integer given as little-endian `u64` limbs modulo the field characteristic.
+    pub fn from_limbs(limbs: &[u64]) -> Self {
+        let shift = Self::new(F::from(1 << 32).square());
+        limbs
+            .iter()
+            .rev()
+            .fold(Self::ZERO, |acc, limb| acc * shift + Self::from(*limb))
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut limbs = [0u64; N];
+        for (i, byte) in bytes.iter().enumerate() {
+            if *byte != 0 {
+                *limbs.get_mut(i / 8)? |= u64::from(*byte) << (8 * (i % 8));
+            }
+        }
+        Self::from_bigint(BigInt::new(limbs))
+    }
+}
+
+impl<F: PrimeField, const N: usize> Default for RustCryptoScalar<F, N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// The default scalar is zero, so zeroizing resets it to its default value
+impl<F: PrimeField, const N: usize> zeroize::DefaultIsZeroes for RustCryptoScalar<F, N> {}
+
+impl<F: PrimeField, const N: usize> PartialEq for RustCryptoScalar<F, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.to_repr().as_ref() == other.inner.to_repr().as_ref()
+    }
+}
+
+impl<F: PrimeField, const N: usize> Eq for RustCryptoScalar<F, N> {}
+
+impl<F: PrimeField, const N: usize> Hash for RustCryptoScalar<F, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.to_repr().as_ref().hash(state);
+    }
+}
+
+impl<F: PrimeField, const N: usize> Ord for RustCryptoScalar<F, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_bigint().cmp(&other.into_bigint())
+    }
+}
+
+impl<F: PrimeField, const N: usize> PartialOrd for RustCryptoScalar<F, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Outputs the value as a decimal without leading zeroes, like the arkworks fields.
+impl<F: PrimeField, const N: usize> fmt::Display for RustCryptoScalar<F, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.into_bigint())
+    }
+}
+
+/// Interprets a decimal string as a (congruent) field element.
+impl<F: PrimeField, const N: usize> FromStr for RustCryptoScalar<F, N> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let modulus = num_bigint::BigInt::from(BigUint::from(Self::MODULUS));
+        let value = s.parse::<num_bigint::BigInt>().map_err(|_| ())? % &modulus;
+        let value = if value.sign() == num_bigint::Sign::Minus {
+            value + modulus
+        } else {
+            value
+        };
+        value.to_biguint().map(Self::from).ok_or(())
+    }
+}
+
+impl<F: PrimeField, const N: usize> Distribution<RustCryptoScalar<F, N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RustCryptoScalar<F, N> {
+        RustCryptoScalar::new(F::random(rng))
+    }
+}
+
+impl<F: PrimeField, const N: usize> Zero for RustCryptoScalar<F, N> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        bool::from(self.inner.is_zero())
+    }
+}
+
+impl<F: PrimeField, const N: usize> One for RustCryptoScalar<F, N> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<F: PrimeField, const N: usize> Neg for RustCryptoScalar<F, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::reduced(-self.inner)
+    }
+}
+
+/// Implements an arithmetic operator and its assigning form for owned, borrowed and mutably
+/// borrowed right-hand sides, all going through `$body` on the inner values.
+macro_rules! impl_scalar_op {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<F: PrimeField, const N: usize> $assign<&Self> for RustCryptoScalar<F, N> {
+            fn $assign_fn(&mut self, other: &Self) {
+                let ($a, $b) = (self.inner, other.inner);
+                *self = $body;
+            }
+        }
+
+        impl<F: PrimeField, const N: usize> $assign for RustCryptoScalar<F, N> {
+            fn $assign_fn(&mut self, other: Self) {
+                self.$assign_fn(&other);
+            }
+        }
+
+        impl<F: PrimeField, const N: usize> $assign<&mut Self> for RustCryptoScalar<F, N> {
+            fn $assign_fn(&mut self, other: &mut Self) {
+                self.$assign_fn(&*other);
+            }
+        }
+
+        impl<F: PrimeField, const N: usize> $op<&Self> for RustCryptoScalar<F, N> {
+            type Output = Self;
+
+            fn $op_fn(mut self, other: &Self) -> Self {
+                self.$assign_fn(other);
+                self
+            }
+        }
+
+        impl<F: PrimeField, const N: usize> $op for RustCryptoScalar<F, N> {
+            type Output = Self;
+
+            fn $op_fn(self, other: Self) -> Self {
+                self.$op_fn(&other)
+            }
+        }
+
+        impl<F: PrimeField, const N: usize> $op<&mut Self> for RustCryptoScalar<F, N> {
+            type Output = Self;
+
+            fn $op_fn(self, other: &mut Self) -> Self {
+                self.$op_fn(&*other)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add, AddAssign, add_assign, |a, b| Self::reduced(a + b));
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign, |a, b| Self::reduced(a - b));
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign, |a, b| Self::new(a * b));
+impl_scalar_op!(Div, div, DivAssign, div_assign, |a, b| Self::new(
+    a.mul(b.invert().expect("division by zero"))
+));
+
+impl<F: PrimeField, const N: usize> Sum for RustCryptoScalar<F, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: PrimeField, const N: usize> Sum<&'a Self> for RustCryptoScalar<F, N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<F: PrimeField, const N: usize> Product for RustCryptoScalar<F, N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: PrimeField, const N: usize> Product<&'a Self> for RustCryptoScalar<F, N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<u128> for RustCryptoScalar<F, N> {
+    fn from(value: u128) -> Self {
+        Self::from_limbs(&[value as u64, (value >> 64) as u64])
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<u64> for RustCryptoScalar<F, N> {
+    fn from(value: u64) -> Self {
+        Self::new(F::from(value))
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<i128> for RustCryptoScalar<F, N> {
+    fn from(value: i128) -> Self {
+        let abs = Self::from(value.unsigned_abs());
+        if value < 0 { -abs } else { abs }
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($via:ty => $($int:ty),*) => {
+        $(
+            impl<F: PrimeField, const N: usize> From<$int> for RustCryptoScalar<F, N> {
+                fn from(value: $int) -> Self {
+                    Self::from(<$via>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_int!(u64 => u32, u16, u8, bool);
+impl_from_small_int!(i128 => i64, i32, i16, i8);
+
+impl<F: PrimeField, const N: usize> From<BigInt<N>> for RustCryptoScalar<F, N> {
+    fn from(value: BigInt<N>) -> Self {
+        Self::from_limbs(value.as_ref())
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<RustCryptoScalar<F, N>> for BigInt<N> {
+    fn from(scalar: RustCryptoScalar<F, N>) -> Self {
+        scalar.into_bigint()
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<BigUint> for RustCryptoScalar<F, N> {
+    fn from(value: BigUint) -> Self {
+        Self::from_le_bytes_mod_order(&value.to_bytes_le())
+    }
+}
+
+impl<F: PrimeField, const N: usize> From<RustCryptoScalar<F, N>> for BigUint {
+    fn from(scalar: RustCryptoScalar<F, N>) -> Self {
+        scalar.into_bigint().into()
+    }
+}
+
+impl<F: PrimeField, const N: usize> CanonicalSerializeWithFlags for RustCryptoScalar<F, N> {
+    fn serialize_with_flags<W: ark_serialize::Write, Fl: Flags>(
+        &self,
+        mut writer: W,
+        flags: Fl,
+    ) -> Result<(), SerializationError> {
+        if Fl::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let size = self.serialized_size_with_flags::<Fl>();
+        let mut bytes = self.into_bigint().to_bytes_le();
+        bytes.resize(size, 0);
+        bytes[size - 1] |= flags.u8_bitmask();
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<Fl: Flags>(&self) -> usize {
+        buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + Fl::BIT_SIZE)
+    }
+}
+
+impl<F: PrimeField, const N: usize> CanonicalSerialize for RustCryptoScalar<F, N> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<F: PrimeField, const N: usize> CanonicalDeserializeWithFlags for RustCryptoScalar<F, N> {
+    fn deserialize_with_flags<R: ark_serialize::Read, Fl: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, Fl), SerializationError> {
+        if Fl::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let size = Self::ZERO.serialized_size_with_flags::<Fl>();
+        let mut bytes = vec![0u8; size];
+        reader.read_exact(&mut bytes)?;
+        let flags = Fl::from_u8_remove_flags(&mut bytes[size - 1])
+            .ok_or(SerializationError::UnexpectedFlags)?;
+        Self::from_le_bytes(&bytes)
+            .map(|scalar| (scalar, flags))
+            .ok_or(SerializationError::InvalidData)
+    }
+}
+
+impl<F: PrimeField, const N: usize> Valid for RustCryptoScalar<F, N> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<F: PrimeField, const N: usize> CanonicalDeserialize for RustCryptoScalar<F, N> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(scalar, _)| scalar)
+    }
+}
+
+impl<F: PrimeField, const N: usize> AdditiveGroup for RustCryptoScalar<F, N> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self::new(F::ZERO);
+
+    fn double(&self) -> Self {
+        Self::reduced(self.inner.double())
+    }
+
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+}
+
+impl<F: PrimeField, const N: usize> Field for RustCryptoScalar<F, N> {
+    type BasePrimeField = Self;
+
+    // Square roots come from `ff::Field::sqrt` instead
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+
+    const ONE: Self = Self::new(F::ONE);
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self> {
+        core::iter::once(*self)
+    }
+
+    fn from_base_prime_field_elems(elems: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut elems = elems.into_iter();
+        let elem = elems.next()?;
+        elems.next().is_none().then_some(elem)
+    }
+
+    fn from_base_prime_field(elem: Self) -> Self {
+        elem
+    }
+
+    fn from_random_bytes_with_flags<Fl: Flags>(bytes: &[u8]) -> Option<(Self, Fl)> {
+        if Fl::BIT_SIZE > 8 {
+            return None;
+        }
+        let size = buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + Fl::BIT_SIZE);
+        let mut buffer = vec![0u8; size];
+        let len = bytes.len().min(size);
+        buffer[..len].copy_from_slice(&bytes[..len]);
+        let flags = Fl::from_u8_remove_flags(&mut buffer[size - 1])?;
+        // Keep only the bits below `MODULUS_BIT_SIZE`, as the arkworks fields do
+        let bits = Self::MODULUS_BIT_SIZE as usize;
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            if 8 * i >= bits {
+                *byte = 0;
+            } else if 8 * (i + 1) > bits {
+                *byte &= (1u8 << (bits - 8 * i)) - 1;
+            }
+        }
+        Self::from_le_bytes(&buffer).map(|scalar| (scalar, flags))
+    }
+
+    fn legendre(&self) -> LegendreSymbol {
+        let s = self.inner.pow_vartime(Self::MODULUS_MINUS_ONE_DIV_TWO);
+        if bool::from(s.is_zero()) {
+            LegendreSymbol::Zero
+        } else if s == F::ONE {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Note: This is not constant-time, because arkworks returns an `Option`
+        Option::from(self.inner.sqrt()).map(Self::new)
+    }
+
+    fn square(&self) -> Self {
+        Self::new(self.inner.square())
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        // Note: This is not constant-time, because arkworks returns an `Option`
+        Option::from(self.inner.invert()).map(Self::new)
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        *self = self.inverse()?;
+        Some(self)
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    fn frobenius_map_in_place(&mut self, _power: usize) {}
+
+    fn mul_by_base_prime_field(&self, elem: &Self) -> Self {
+        *self * elem
+    }
+}
+
+impl<F: PrimeField, const N: usize> FftField for RustCryptoScalar<F, N> {
+    const GENERATOR: Self = Self::new(F::MULTIPLICATIVE_GENERATOR);
+    const TWO_ADICITY: u32 = F::S;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::new(F::ROOT_OF_UNITY);
+}
+
+impl<F: PrimeField, const N: usize> ArkPrimeField for RustCryptoScalar<F, N> {
+    type BigInt = BigInt<N>;
+
+    const MODULUS: BigInt<N> = modulus_from_hex(F::MODULUS);
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInt<N> = Self::MODULUS.divide_by_2_round_down();
+    const MODULUS_BIT_SIZE: u32 = F::NUM_BITS;
+    const TRACE: BigInt<N> = Self::MODULUS.two_adic_coefficient();
+    const TRACE_MINUS_ONE_DIV_TWO: BigInt<N> = Self::TRACE.divide_by_2_round_down();
+
+    fn from_bigint(repr: BigInt<N>) -> Option<Self> {
+        if repr >= Self::MODULUS {
+            return None;
+        }
+        let mut bytes = F::Repr::default();
+        let len = bytes.as_ref().len();
+        for (byte, value) in bytes.as_mut().iter_mut().zip(repr.to_bytes_le()) {
+            *byte = value;
+        }
+        if !Self::repr_is_little_endian() {
+            bytes.as_mut()[..len].reverse();
+        }
+        Option::from(F::from_repr(bytes)).map(Self::new)
+    }
+
+    fn into_bigint(self) -> BigInt<N> {
+        let mut bytes = self.inner.to_repr();
+        if !Self::repr_is_little_endian() {
+            bytes.as_mut().reverse();
+        }
+        let mut limbs = [0u64; N];
+        for (i, byte) in bytes.as_ref().iter().enumerate() {
+            if *byte != 0 {
+                limbs[i / 8] |= u64::from(*byte) << (8 * (i % 8));
+            }
+        }
+        BigInt::new(limbs)
+    }
+}
+
+/// A RustCrypto prime-order short Weierstrass curve that [`RustCryptoPoint`] can present to
+/// arkworks. The `ff` and `group` traits say nothing about coordinates, so each curve provides
+/// them here.
+pub trait RustCryptoCurve: PrimeCurve {
+    type BaseField: PrimeField;
+
+    const IDENTITY: Self;
+    const GENERATOR: Self;
+
+    /// Returns the affine coordinates, or `None` for the identity.
+    fn xy(point: &Self::Affine) -> Option<(Self::BaseField, Self::BaseField)>;
+
+    /// Returns the point with these coordinates, if it is on the curve.
+    fn from_xy(x: &Self::BaseField, y: &Self::BaseField) -> Option<Self::Affine>;
+
+    /// Returns one of the two points with this `x` coordinate, if there are any.
+    fn from_x(x: &Self::BaseField) -> Option<Self::Affine>;
+}
+
+type ScalarField<G, const N: usize> = RustCryptoScalar<<G as Group>::Scalar, N>;
+type BaseField<G, const N: usize> = RustCryptoScalar<<G as RustCryptoCurve>::BaseField, N>;
+
+/// The arkworks curve parameters of a [`RustCryptoCurve`]. The `group::prime` traits only
+/// describe prime-order groups, so the cofactor is always one.
+pub struct RustCryptoConfig<G, const N: usize = 4>(PhantomData<fn() -> G>);
+
+impl<G: RustCryptoCurve, const N: usize> CurveConfig for RustCryptoConfig<G, N> {
+    type BaseField = BaseField<G, N>;
+    type ScalarField = ScalarField<G, N>;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Self::ScalarField = RustCryptoScalar::ONE;
+}
+
+/// Wraps a RustCrypto projective point to implement `ark_ec::CurveGroup`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RustCryptoPoint<G: RustCryptoCurve, const N: usize = 4> {
+    pub inner: G,
+}
+
+/// Wraps a RustCrypto affine point to implement `ark_ec::AffineRepr`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RustCryptoAffine<G: RustCryptoCurve, const N: usize = 4> {
+    pub inner: G::Affine,
+}
+
+impl<G: RustCryptoCurve, const N: usize> RustCryptoPoint<G, N> {
+    pub const fn new(point: G) -> Self {
+        Self { inner: point }
+    }
+
+    pub const fn into_inner(self) -> G {
+        self.inner
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> RustCryptoAffine<G, N> {
+    pub const fn new(point: G::Affine) -> Self {
+        Self { inner: point }
+    }
+
+    pub const fn into_inner(self) -> G::Affine {
+        self.inner
+    }
+
+    /// Returns the point with this `x` coordinate, choosing `y` by the arkworks sign convention.
+    fn from_x(x: BaseField<G, N>, y_is_positive: bool) -> Option<Self> {
+        let point = Self::new(G::from_x(&x.inner)?);
+        let (_, y) = point.xy()?;
+        Some(if (y <= -y) == y_is_positive {
+            point
+        } else {
+            -point
+        })
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Default for RustCryptoPoint<G, N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Default for RustCryptoAffine<G, N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// The default point is the identity, so zeroizing resets it to its default value
+impl<G: RustCryptoCurve, const N: usize> zeroize::DefaultIsZeroes for RustCryptoPoint<G, N> {}
+
+impl<G: RustCryptoCurve, const N: usize> zeroize::DefaultIsZeroes for RustCryptoAffine<G, N> {}
+
+impl<G: RustCryptoCurve, const N: usize> Hash for RustCryptoPoint<G, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state);
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Hash for RustCryptoAffine<G, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.to_bytes().as_ref().hash(state);
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> fmt::Display for RustCryptoPoint<G, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.into_affine())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> fmt::Display for RustCryptoAffine<G, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.xy() {
+            Some((x, y)) => write!(f, "({x}, {y})"),
+            None => write!(f, "infinity"),
+        }
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Distribution<RustCryptoPoint<G, N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RustCryptoPoint<G, N> {
+        RustCryptoPoint::new(G::random(rng))
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Distribution<RustCryptoAffine<G, N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RustCryptoAffine<G, N> {
+        RustCryptoAffine::new(G::random(rng).to_affine())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> CanonicalSerialize for RustCryptoAffine<G, N> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let (x, y, flags) = match self.xy() {
+            Some((x, y)) => (x, y, SWFlags::from_y_coordinate(y)),
+            None => (
+                RustCryptoScalar::ZERO,
+                RustCryptoScalar::ZERO,
+                SWFlags::infinity(),
+            ),
+        };
+        match compress {
+            Compress::Yes => x.serialize_with_flags(writer, flags),
+            Compress::No => {
+                x.serialize_with_mode(&mut writer, compress)?;
+                y.serialize_with_flags(writer, flags)
+            }
+        }
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let zero = BaseField::<G, N>::ZERO;
+        match compress {
+            Compress::Yes => zero.serialized_size_with_flags::<SWFlags>(),
+            Compress::No => zero.compressed_size() + zero.serialized_size_with_flags::<SWFlags>(),
+        }
+    }
+}
+
+// The RustCrypto types only hold points on the curve, which has prime order
+impl<G: RustCryptoCurve, const N: usize> Valid for RustCryptoAffine<G, N> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+/// Decoding always checks that the point is on the curve, because the RustCrypto types cannot
+/// hold any other point.
+impl<G: RustCryptoCurve, const N: usize> CanonicalDeserialize for RustCryptoAffine<G, N> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        match compress {
+            Compress::Yes => {
+                let (x, flags): (BaseField<G, N>, SWFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                match flags.is_positive() {
+                    None => Ok(Self::zero()),
+                    Some(y_is_positive) => {
+                        Self::from_x(x, y_is_positive).ok_or(SerializationError::InvalidData)
+                    }
+                }
+            }
+            Compress::No => {
+                let x: BaseField<G, N> =
+                    CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+                let (y, flags): (BaseField<G, N>, SWFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                if flags.is_infinity() {
+                    return Ok(Self::zero());
+                }
+                G::from_xy(&x.inner, &y.inner)
+                    .map(Self::new)
+                    .ok_or(SerializationError::InvalidData)
+            }
+        }
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> CanonicalSerialize for RustCryptoPoint<G, N> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.into_affine().serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        RustCryptoAffine::<G, N>::zero().serialized_size(compress)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Valid for RustCryptoPoint<G, N> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> CanonicalDeserialize for RustCryptoPoint<G, N> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        RustCryptoAffine::<G, N>::deserialize_with_mode(reader, compress, validate).map(Self::from)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> From<RustCryptoAffine<G, N>> for RustCryptoPoint<G, N> {
+    fn from(point: RustCryptoAffine<G, N>) -> Self {
+        Self::new(point.inner.to_curve())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> From<RustCryptoPoint<G, N>> for RustCryptoAffine<G, N> {
+    fn from(point: RustCryptoPoint<G, N>) -> Self {
+        Self::new(point.inner.to_affine())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Zero for RustCryptoPoint<G, N> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        bool::from(self.inner.is_identity())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Neg for RustCryptoPoint<G, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.inner)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Neg for RustCryptoAffine<G, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.inner)
+    }
+}
+
+/// Implements an operator of `RustCryptoPoint` and its assigning form for owned, borrowed and
+/// (when `$rhs` is `Self`) mutably borrowed right-hand sides, using the RustCrypto operator on
+/// the inner values.
+macro_rules! impl_point_op {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $rhs:ty) => {
+        impl<G: RustCryptoCurve, const N: usize> $assign<&$rhs> for RustCryptoPoint<G, N> {
+            fn $assign_fn(&mut self, other: &$rhs) {
+                self.inner.$assign_fn(other.inner);
+            }
+        }
+
+        impl<G: RustCryptoCurve, const N: usize> $assign<$rhs> for RustCryptoPoint<G, N> {
+            fn $assign_fn(&mut self, other: $rhs) {
+                self.$assign_fn(&other);
+            }
+        }
+
+        impl<G: RustCryptoCurve, const N: usize> $op<&$rhs> for RustCryptoPoint<G, N> {
+            type Output = Self;
+
+            fn $op_fn(mut self, other: &$rhs) -> Self {
+                self.$assign_fn(other);
+                self
+            }
+        }
+
+        impl<G: RustCryptoCurve, const N: usize> $op<$rhs> for RustCryptoPoint<G, N> {
+            type Output = Self;
+
+            fn $op_fn(self, other: $rhs) -> Self {
+                self.$op_fn(&other)
+            }
+        }
+    };
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $rhs:ty, mut) => {
+        impl_point_op!($op, $op_fn, $assign, $assign_fn, $rhs);
+
+        impl<G: RustCryptoCurve, const N: usize> $assign<&mut $rhs> for RustCryptoPoint<G, N> {
+            fn $assign_fn(&mut self, other: &mut $rhs) {
+                self.$assign_fn(&*other);
+            }
+        }
+
+        impl<G: RustCryptoCurve, const N: usize> $op<&mut $rhs> for RustCryptoPoint<G, N> {
+            type Output = Self;
+
+            fn $op_fn(self, other: &mut $rhs) -> Self {
+                self.$op_fn(&*other)
+            }
+        }
+    };
+}
+
+impl_point_op!(Add, add, AddAssign, add_assign, Self, mut);
+impl_point_op!(Sub, sub, SubAssign, sub_assign, Self, mut);
+impl_point_op!(Mul, mul, MulAssign, mul_assign, ScalarField<G, N>, mut);
+impl_point_op!(Add, add, AddAssign, add_assign, RustCryptoAffine<G, N>);
+impl_point_op!(Sub, sub, SubAssign, sub_assign, RustCryptoAffine<G, N>);
+
+/// Implements an operator of `RustCryptoAffine` returning a `RustCryptoPoint`, for owned and
+/// borrowed right-hand sides.
+macro_rules! impl_affine_op {
+    ($op:ident, $op_fn:ident, $rhs:ty, |$a:ident, $b:ident| $body:expr) => {
+        impl<G: RustCryptoCurve, const N: usize> $op<&$rhs> for RustCryptoAffine<G, N> {
+            type Output = RustCryptoPoint<G, N>;
+
+            fn $op_fn(self, other: &$rhs) -> RustCryptoPoint<G, N> {
+                let ($a, $b) = (self.inner, other.inner);
+                RustCryptoPoint::new($body)
+            }
+        }
+
+        impl<G: RustCryptoCurve, const N: usize> $op<$rhs> for RustCryptoAffine<G, N> {
+            type Output = RustCryptoPoint<G, N>;
+
+            fn $op_fn(self, other: $rhs) -> RustCryptoPoint<G, N> {
+                self.$op_fn(&other)
+            }
+        }
+    };
+}
+
+impl_affine_op!(Add, add, Self, |a, b| a.to_curve() + b);
+impl_affine_op!(Sub, sub, Self, |a, b| a.to_curve() - b);
+impl_affine_op!(Add, add, RustCryptoPoint<G, N>, |a, b| b + a);
+impl_affine_op!(Sub, sub, RustCryptoPoint<G, N>, |a, b| -b + a);
+impl_affine_op!(Mul, mul, ScalarField<G, N>, |a, b| a * b);
+
+impl<G: RustCryptoCurve, const N: usize> Sum for RustCryptoPoint<G, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, G: RustCryptoCurve, const N: usize> Sum<&'a Self> for RustCryptoPoint<G, N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> Sum<RustCryptoAffine<G, N>> for RustCryptoPoint<G, N> {
+    fn sum<I: Iterator<Item = RustCryptoAffine<G, N>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, G: RustCryptoCurve, const N: usize> Sum<&'a RustCryptoAffine<G, N>>
+    for RustCryptoPoint<G, N>
+{
+    fn sum<I: Iterator<Item = &'a RustCryptoAffine<G, N>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> AdditiveGroup for RustCryptoPoint<G, N> {
+    type Scalar = ScalarField<G, N>;
+
+    const ZERO: Self = Self::new(G::IDENTITY);
+
+    fn double(&self) -> Self {
+        Self::new(self.inner.double())
+    }
+
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> PrimeGroup for RustCryptoPoint<G, N> {
+    type ScalarField = ScalarField<G, N>;
+
+    fn generator() -> Self {
+        Self::new(G::GENERATOR)
+    }
+
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        *self * ScalarField::<G, N>::from_limbs(other.as_ref())
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> ScalarMul for RustCryptoPoint<G, N> {
+    type MulBase = RustCryptoAffine<G, N>;
+
+    const NEGATION_IS_CHEAP: bool = true;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<Self::MulBase> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> VariableBaseMSM for RustCryptoPoint<G, N> {}
+
+impl<G: RustCryptoCurve, const N: usize> CurveGroup for RustCryptoPoint<G, N> {
+    type Config = RustCryptoConfig<G, N>;
+    type BaseField = BaseField<G, N>;
+    type Affine = RustCryptoAffine<G, N>;
+    type FullGroup = RustCryptoAffine<G, N>;
+
+    fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
+        let points: Vec<G> = v.iter().map(|point| point.inner).collect();
+        let mut affine = vec![G::Affine::identity(); points.len()];
+        G::batch_normalize(&points, &mut affine);
+        affine.into_iter().map(RustCryptoAffine::new).collect()
+    }
+}
+
+impl<G: RustCryptoCurve, const N: usize> AffineRepr for RustCryptoAffine<G, N> {
+    type Config = RustCryptoConfig<G, N>;
+    type ScalarField = ScalarField<G, N>;
+    type BaseField = BaseField<G, N>;
+    type Group = RustCryptoPoint<G, N>;
+
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)> {
+        G::xy(&self.inner).map(|(x, y)| (RustCryptoScalar::new(x), RustCryptoScalar::new(y)))
+    }
+
+    fn zero() -> Self {
+        Self::new(G::Affine::identity())
+    }
+
+    fn generator() -> Self {
+        Self::new(G::Affine::generator())
+    }
+
+    /// Reads an `x` coordinate and `SWFlags` like the arkworks short Weierstrass curves.
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        let (x, flags) = BaseField::<G, N>::from_random_bytes_with_flags::<SWFlags>(bytes)?;
+        match flags.is_positive() {
+            None => x.is_zero().then(Self::zero),
+            Some(y_is_positive) => Self::from_x(x, y_is_positive),
+        }
+    }
+
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Self::Group {
+        *self * ScalarField::<G, N>::from_limbs(by.as_ref())
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        *self
+    }
+
+    fn mul_by_cofactor_to_group(&self) -> Self::Group {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "k256")]
+impl RustCryptoCurve for k256::ProjectivePoint {
+    type BaseField = k256::FieldElement;
+
+    const IDENTITY: Self = k256::ProjectivePoint::IDENTITY;
+    const GENERATOR: Self = k256::ProjectivePoint::GENERATOR;
+
+    fn xy(point: &k256::AffinePoint) -> Option<(k256::FieldElement, k256::FieldElement)> {
+        use elliptic_curve::sec1::ToEncodedPoint;
+
+        let encoded = point.to_encoded_point(false);
+        let x = k256::FieldElement::from_repr(*encoded.x()?);
+        let y = k256::FieldElement::from_repr(*encoded.y()?);
+        Option::from(x.and_then(|x| y.map(|y| (x, y))))
+    }
+
+    fn from_xy(x: &k256::FieldElement, y: &k256::FieldElement) -> Option<k256::AffinePoint> {
+        use elliptic_curve::sec1::FromEncodedPoint;
+
+        let encoded =
+            k256::EncodedPoint::from_affine_coordinates(&x.to_repr(), &y.to_repr(), false);
+        Option::from(k256::AffinePoint::from_encoded_point(&encoded))
+    }
+
+    fn from_x(x: &k256::FieldElement) -> Option<k256::AffinePoint> {
+        use elliptic_curve::point::DecompressPoint;
+
+        Option::from(k256::AffinePoint::decompress(
+            &x.to_repr(),
+            subtle::Choice::from(0),
+        ))
+    }
+}
@@ -1,7 +1,17 @@
-use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
-
-#[derive(Clone, Debug, Default, Copy)]
+use ark_ff::{
+    AdditiveGroup, BigInt, BigInteger, FftField, Fp, MontBackend, MontConfig,
+    PrimeField as ArkPrimeField,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use elliptic_curve::bigint::{Encoding, NonZero, Uint};
+use elliptic_curve::ops::{Reduce, ReduceNonZero};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[derive(Clone, Default, Copy)]
 pub struct ScalarRepr<F: ArkPrimeField> {
     inner: F::BigInt,
 }
@@ -10,6 +20,10 @@ impl<F: ArkPrimeField> ScalarRepr<F> {
     pub const fn new(bigint: F::BigInt) -> Self {
         Self { inner: bigint }
     }
+
+    pub const fn into_bigint(self) -> F::BigInt {
+        self.inner
+    }
 }
 
 impl<F: ArkPrimeField> AsRef<[u8]> for ScalarRepr<F> {
@@ -34,6 +48,10 @@ impl<F: ArkPrimeField> AsMut<[u8]> for ScalarRepr<F> {
     }
 }
 
+// The default scalar and repr are both zero, so zeroizing resets them to their default value
+#[cfg(feature = "zeroize")]
+impl<F: ArkPrimeField> zeroize::DefaultIsZeroes for ScalarRepr<F> {}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Copy)]
 pub struct ArkScalarWrapper<F: ArkPrimeField> {
     pub inner: F,
@@ -49,6 +67,9 @@ impl<F: ArkPrimeField> ArkScalarWrapper<F> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<F: ArkPrimeField> zeroize::DefaultIsZeroes for ArkScalarWrapper<F> {}
+
 impl<F: ArkPrimeField> ConditionallySelectable for ArkScalarWrapper<F> {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         if choice.into() { *b } else { *a }
@@ -57,10 +78,12 @@ impl<F: ArkPrimeField> ConditionallySelectable for ArkScalarWrapper<F> {
 
 impl<F: ArkPrimeField> ConstantTimeEq for ArkScalarWrapper<F> {
     fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        // Compare the canonical limbs in place rather than going through `to_bytes_be`,
+        // which would allocate two vectors on every comparison.
         self.inner
             .into_bigint()
-            .to_bytes_be()
-            .ct_eq(&other.inner.into_bigint().to_bytes_be())
+            .as_ref()
+            .ct_eq(other.inner.into_bigint().as_ref())
     }
 }
 
@@ -70,257 +93,513 @@ impl<F: ArkPrimeField> From<u64> for ArkScalarWrapper<F> {
     }
 }
 
-#[macro_export]
-macro_rules! impl_wrapped_field {
-    ($field:ty, $constants:ident) => {
-        impl ff::Field for $crate::ArkScalarWrapper<$field> {
-            const ZERO: Self = unsafe {
-                // SAFETY: This is safe because we're creating a known-good zero value
-                let bytes: [u8; std::mem::size_of::<Self>()] = $crate::$constants::ZERO;
-                std::mem::transmute(bytes)
-            };
-            const ONE: Self = unsafe {
-                // SAFETY: This is safe because we're creating a known-good one value
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::ONE;
-                std::mem::transmute(bytes)
-            };
-
-            fn random(mut rng: impl rand_core::RngCore) -> Self {
-                use ark_ff::UniformRand;
-                Self::new(<$field>::rand(&mut rng))
-            }
+/// Packs the little-endian bits of `bigint` into `N` limbs for `ff::PrimeFieldBits`.
+pub(crate) fn to_field_bits<B: BigInteger, const N: usize>(bigint: &B) -> ff::FieldBits<[u64; N]> {
+    let mut bits = ff::FieldBits::<[u64; N]>::ZERO;
+    for (i, bit) in bigint.to_bits_le().into_iter().enumerate() {
+        bits.set(i, bit);
+    }
+    bits
+}
 
-            fn is_zero(&self) -> subtle::Choice {
-                use ark_ff::Zero;
-                subtle::Choice::from(self.inner.is_zero() as u8)
-            }
+/// Reduces `N` little-endian bytes modulo the field characteristic. `N` must exceed the field size
+/// by at least 128 bits to keep the bias negligible, which is checked at compile time: 48 and 64
+/// bytes suit 256-bit fields, and 64 or more bytes suit 384-bit ones.
+impl<F, const N: usize> ff::FromUniformBytes<N> for ArkScalarWrapper<F>
+where
+    F: ArkPrimeField,
+    Self: ff::PrimeField,
+{
+    fn from_uniform_bytes(bytes: &[u8; N]) -> Self {
+        const {
+            assert!(
+                8 * N >= F::MODULUS_BIT_SIZE as usize + 128,
+                "too few bytes to reduce to a uniform scalar"
+            )
+        };
+        // Note: This is not constant-time, but arkworks doesn't provide constant-time reduction
+        Self::new(F::from_le_bytes_mod_order(bytes))
+    }
+}
 
-            fn square(&self) -> Self {
-                use ark_ff::Field;
-                Self::new(self.inner.square())
-            }
+/// Returns the field characteristic minus `offset` as a `LIMBS`-limb integer, or `None` if the
+/// characteristic does not fit, in which case every such integer is already reduced.
+fn modulus_minus<F: ArkPrimeField, const LIMBS: usize>(offset: u64) -> Option<Uint<LIMBS>> {
+    if F::MODULUS_BIT_SIZE as usize > Uint::<LIMBS>::BITS {
+        return None;
+    }
+    let mut modulus = F::MODULUS;
+    modulus.sub_with_borrow(&F::BigInt::from(offset));
+    let mut words = [0; LIMBS];
+    let limbs = modulus.as_ref();
+    let len = limbs.len().min(LIMBS);
+    words[..len].copy_from_slice(&limbs[..len]);
+    Some(Uint::from_words(words))
+}
 
-            fn double(&self) -> Self {
-                use ark_ff::AdditiveGroup;
-                Self::new(self.inner.double())
-            }
+/// Converts an integer that is already reduced modulo the field characteristic into Montgomery
+/// form in constant time, by a Montgomery multiplication with `R^2`.
+fn from_reduced_uint<T: MontConfig<N>, const N: usize, const LIMBS: usize>(
+    n: &Uint<LIMBS>,
+) -> MontField<T, N> {
+    let mut bigint = BigInt::<N>::zero();
+    let len = N.min(LIMBS);
+    bigint.0[..len].copy_from_slice(&n.as_words()[..len]);
+    Fp::new_unchecked(mont_mul::<T, N>(&bigint, &T::R2))
+}
 
-            fn invert(&self) -> subtle::CtOption<Self> {
-                use ark_ff::Field;
-                self.inner.inverse().map_or(
-                    subtle::CtOption::new(Self::ZERO, subtle::Choice::from(0)),
-                    |inv| subtle::CtOption::new(Self::new(inv), subtle::Choice::from(1)),
-                )
-            }
+/// Reduces integers of any width, such as `U256` and `U384` digests or wide `U512` and `U768`
+/// inputs, modulo the field characteristic in constant time.
+impl<T: MontConfig<N>, const N: usize, const LIMBS: usize> Reduce<Uint<LIMBS>>
+    for ArkScalarWrapper<MontField<T, N>>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Bytes = <Uint<LIMBS> as Encoding>::Repr;
+
+    fn reduce(n: Uint<LIMBS>) -> Self {
+        let reduced = match modulus_minus::<MontField<T, N>, LIMBS>(0) {
+            // Note: This unwrap is safe because the field characteristic is non-zero
+            Some(modulus) => n.rem(&NonZero::new(modulus).unwrap()),
+            None => n,
+        };
+        Self::new(from_reduced_uint(&reduced))
+    }
 
-            fn sqrt_ratio(num: &Self, div: &Self) -> (subtle::Choice, Self) {
-                use ark_ff::Field;
-                let num_is_square = num.inner.sqrt().is_some();
-                let div_is_square = div.inner.sqrt().is_some();
-                let is_ratio_square = num_is_square && div_is_square;
-
-                let value = if is_ratio_square {
-                    let num_sqrt = num.inner.sqrt().unwrap();
-                    let div_sqrt = div.inner.sqrt().unwrap();
-                    let ratio = num_sqrt * div_sqrt.inverse().unwrap();
-                    Self::new(ratio)
-                } else {
-                    Self::ZERO
-                };
+    fn reduce_bytes(bytes: &Self::Bytes) -> Self {
+        Self::reduce(Uint::from_be_bytes(*bytes))
+    }
+}
 
-                (subtle::Choice::from(is_ratio_square as u8), value)
-            }
-        }
+/// Reduces modulo the field characteristic minus one and adds one, so the result is never zero.
+impl<T: MontConfig<N>, const N: usize, const LIMBS: usize> ReduceNonZero<Uint<LIMBS>>
+    for ArkScalarWrapper<MontField<T, N>>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn reduce_nonzero(n: Uint<LIMBS>) -> Self {
+        let reduced = match modulus_minus::<MontField<T, N>, LIMBS>(1) {
+            // Note: This unwrap is safe because the field characteristic is greater than two
+            Some(modulus) => n.rem(&NonZero::new(modulus).unwrap()),
+            None => n,
+        };
+        Self::new(
+            from_reduced_uint::<T, N, LIMBS>(&reduced) + <MontField<T, N> as ark_ff::Field>::ONE,
+        )
+    }
 
-        // Implement arithmetic operations
-        impl core::ops::Add for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            type Output = Self;
-            fn add(self, other: Self) -> Self {
-                Self::new(self.inner + other.inner)
-            }
-        }
+    fn reduce_nonzero_bytes(bytes: &Self::Bytes) -> Self {
+        Self::reduce_nonzero(Uint::from_be_bytes(*bytes))
+    }
+}
 
-        impl<'a> core::ops::Add<&'a Self> for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            type Output = Self;
-            fn add(self, other: &Self) -> Self {
-                Self::new(self.inner + other.inner)
-            }
-        }
+impl<F: ArkPrimeField> CanonicalSerialize for ArkScalarWrapper<F> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.inner.serialize_with_mode(writer, compress)
+    }
 
-        impl core::ops::AddAssign for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            fn add_assign(&mut self, other: Self) {
-                self.inner += other.inner;
-            }
-        }
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.inner.serialized_size(compress)
+    }
+}
 
-        impl<'a> core::ops::AddAssign<&'a Self> for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            fn add_assign(&mut self, other: &Self) {
-                self.inner += other.inner;
-            }
-        }
+impl<F: ArkPrimeField> Valid for ArkScalarWrapper<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.inner.check()
+    }
+}
 
-        impl core::ops::Sub for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            type Output = Self;
-            fn sub(self, other: Self) -> Self {
-                Self::new(self.inner - other.inner)
-            }
-        }
+impl<F: ArkPrimeField> CanonicalDeserialize for ArkScalarWrapper<F> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        F::deserialize_with_mode(reader, compress, validate).map(Self::new)
+    }
+}
 
-        impl<'a> core::ops::Sub<&'a Self> for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            type Output = Self;
-            fn sub(self, other: &Self) -> Self {
-                Self::new(self.inner - other.inner)
-            }
-        }
+// The `ff` traits are implemented once for every arkworks prime field rather than per field, so
+// that fields from any arkworks curve crate work without generated constants.
+impl<F: ArkPrimeField> ff::Field for ArkScalarWrapper<F> {
+    const ZERO: Self = Self::new(F::ZERO);
+    const ONE: Self = Self::new(F::ONE);
 
-        impl core::ops::SubAssign for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            fn sub_assign(&mut self, other: Self) {
-                self.inner -= other.inner;
-            }
-        }
+    fn random(mut rng: impl rand_core::RngCore) -> Self {
+        Self::new(F::rand(&mut rng))
+    }
 
-        impl<'a> core::ops::SubAssign<&'a Self> for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            fn sub_assign(&mut self, other: &Self) {
-                self.inner -= other.inner;
-            }
-        }
+    fn is_zero(&self) -> Choice {
+        Choice::from(u8::from(self.inner.is_zero()))
+    }
 
-        impl core::ops::Mul for crate::ArkScalarWrapper<$field>
-        where
-            $field: ark_ff::Field,
-        {
-            type Output = Self;
-            fn mul(self, other: Self) -> Self {
-                Self::new(self.inner * other.inner)
-            }
-        }
+    fn square(&self) -> Self {
+        Self::new(self.inner.square())
+    }
 
-        impl<'a> core::ops::Mul<&'a Self> for crate::ArkScalarWrapper<$field> {
-            type Output = Self;
-            fn mul(self, other: &Self) -> Self {
-                Self::new(self.inner * other.inner)
-            }
-        }
+    fn double(&self) -> Self {
+        Self::new(self.inner.double())
+    }
 
-        impl core::ops::MulAssign for crate::ArkScalarWrapper<$field> {
-            fn mul_assign(&mut self, other: Self) {
-                self.inner *= other.inner;
-            }
-        }
+    fn invert(&self) -> CtOption<Self> {
+        self.inner
+            .inverse()
+            .map_or(CtOption::new(Self::new(F::ZERO), Choice::from(0)), |inv| {
+                CtOption::new(Self::new(inv), Choice::from(1))
+            })
+    }
 
-        impl<'a> core::ops::MulAssign<&'a Self> for crate::ArkScalarWrapper<$field> {
-            fn mul_assign(&mut self, other: &Self) {
-                self.inner *= other.inner;
-            }
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        let num_is_square = num.inner.sqrt().is_some();
+        let div_is_square = div.inner.sqrt().is_some();
+        let is_ratio_square = num_is_square && div_is_square;
+
+        let value = if is_ratio_square {
+            let num_sqrt = num.inner.sqrt().unwrap();
+            let div_sqrt = div.inner.sqrt().unwrap();
+            let ratio = num_sqrt * div_sqrt.inverse().unwrap();
+            Self::new(ratio)
+        } else {
+            Self::new(F::ZERO)
+        };
+
+        (Choice::from(is_ratio_square as u8), value)
+    }
+}
+
+impl<F: ArkPrimeField> Add for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.inner + other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> Add<&Self> for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        Self::new(self.inner + other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> AddAssign for ArkScalarWrapper<F> {
+    fn add_assign(&mut self, other: Self) {
+        self.inner += other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> AddAssign<&Self> for ArkScalarWrapper<F> {
+    fn add_assign(&mut self, other: &Self) {
+        self.inner += other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> Sub for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.inner - other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> Sub<&Self> for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn sub(self, other: &Self) -> Self {
+        Self::new(self.inner - other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> SubAssign for ArkScalarWrapper<F> {
+    fn sub_assign(&mut self, other: Self) {
+        self.inner -= other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> SubAssign<&Self> for ArkScalarWrapper<F> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.inner -= other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> Mul for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.inner * other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> Mul<&Self> for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn mul(self, other: &Self) -> Self {
+        Self::new(self.inner * other.inner)
+    }
+}
+
+impl<F: ArkPrimeField> MulAssign for ArkScalarWrapper<F> {
+    fn mul_assign(&mut self, other: Self) {
+        self.inner *= other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> MulAssign<&Self> for ArkScalarWrapper<F> {
+    fn mul_assign(&mut self, other: &Self) {
+        self.inner *= other.inner;
+    }
+}
+
+impl<F: ArkPrimeField> Neg for ArkScalarWrapper<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.inner)
+    }
+}
+
+impl<F: ArkPrimeField> core::iter::Sum for ArkScalarWrapper<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: ArkPrimeField> core::iter::Sum<&'a Self> for ArkScalarWrapper<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<F: ArkPrimeField> core::iter::Product for ArkScalarWrapper<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ONE), |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: ArkPrimeField> core::iter::Product<&'a Self> for ArkScalarWrapper<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::ONE), |acc, x| acc * x)
+    }
+}
+
+/// An arkworks prime field in Montgomery form, which is how every arkworks curve crate defines its
+/// fields.
+type MontField<T, const N: usize> = Fp<MontBackend<T, N>, N>;
+
+/// The longest modulus that `ff::PrimeField::MODULUS` can be rendered for, in limbs.
+const MAX_MODULUS_LIMBS: usize = 16;
+const MAX_MODULUS_HEX_LEN: usize = 2 + 16 * MAX_MODULUS_LIMBS;
+
+/// Montgomery multiplication on raw limbs (CIOS), since the one in arkworks is private. Used to
+/// evaluate `ff::PrimeField` constants at compile time and to convert reduced integers into
+/// Montgomery form, so it runs in constant time.
+const fn mont_mul<T: MontConfig<N>, const N: usize>(a: &BigInt<N>, b: &BigInt<N>) -> BigInt<N> {
+    let modulus = T::MODULUS.0;
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+    let mut i = 0;
+    while i < N {
+        // t += a * b[i]
+        let mut carry = 0u128;
+        let mut j = 0;
+        while j < N {
+            let sum = t[j] as u128 + a.0[j] as u128 * b.0[i] as u128 + carry;
+            t[j] = sum as u64;
+            carry = sum >> 64;
+            j += 1;
+        }
+        let sum = t_hi as u128 + carry;
+        t_hi = sum as u64;
+        let t_top = (sum >> 64) as u64;
+
+        // t = (t + m * modulus) / 2^64, with m chosen so that the division is exact
+        let m = t[0].wrapping_mul(T::INV);
+        let mut carry = (t[0] as u128 + m as u128 * modulus[0] as u128) >> 64;
+        let mut j = 1;
+        while j < N {
+            let sum = t[j] as u128 + m as u128 * modulus[j] as u128 + carry;
+            t[j - 1] = sum as u64;
+            carry = sum >> 64;
+            j += 1;
         }
+        let sum = t_hi as u128 + carry;
+        t[N - 1] = sum as u64;
+        t_hi = t_top + (sum >> 64) as u64;
+        i += 1;
+    }
+
+    // The result is below twice the modulus, so at most one subtraction reduces it. The
+    // subtraction is always computed and then selected with a mask, so that the running time
+    // does not depend on the operands.
+    let mut diff = [0u64; N];
+    let mut borrow = 0u64;
+    let mut j = 0;
+    while j < N {
+        let (d, b1) = t[j].overflowing_sub(modulus[j]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        diff[j] = d;
+        borrow = (b1 | b2) as u64;
+        j += 1;
+    }
+    // Keep `t` only if it has no top limb and subtracting the modulus borrowed
+    let keep = ((t_hi ^ 1) & borrow).wrapping_neg();
+    let mut j = 0;
+    while j < N {
+        t[j] = (t[j] & keep) | (diff[j] & !keep);
+        j += 1;
+    }
+    BigInt(t)
+}
 
-        impl core::ops::Neg for crate::ArkScalarWrapper<$field> {
-            type Output = Self;
-            fn neg(self) -> Self {
-                Self::new(-self.inner)
+/// The `ff::PrimeField` constants that arkworks doesn't provide, derived from the ones it does.
+struct MontConstants<T, const N: usize>(PhantomData<T>);
+
+impl<T: MontConfig<N>, const N: usize> MontConstants<T, N> {
+    /// `(p + 1) / 2`, which is the inverse of two for odd `p`.
+    const TWO_INV: MontField<T, N> = {
+        let mut limbs = T::MODULUS.0;
+        let mut i = 0;
+        while i < N {
+            limbs[i] >>= 1;
+            if i + 1 < N {
+                limbs[i] |= limbs[i + 1] << 63;
             }
+            i += 1;
         }
-
-        impl core::iter::Sum for crate::ArkScalarWrapper<$field> {
-            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-                use ff::Field;
-                iter.fold(Self::ZERO, |acc, x| acc + x)
+        // Add one to `(p - 1) / 2`, which is below `p`, so the carry never leaves the top limb
+        let mut i = 0;
+        while i < N {
+            let (sum, carry) = limbs[i].overflowing_add(1);
+            limbs[i] = sum;
+            if !carry {
+                break;
             }
+            i += 1;
         }
+        Fp::new(BigInt(limbs))
+    };
 
-        impl<'a> core::iter::Sum<&'a Self> for crate::ArkScalarWrapper<$field> {
-            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-                use ff::Field;
-                iter.fold(Self::ZERO, |acc, x| acc + x)
-            }
+    /// `ROOT_OF_UNITY^(2^S - 1)`, the inverse of a primitive `2^S`-th root of unity.
+    const ROOT_OF_UNITY_INV: MontField<T, N> = {
+        let root = T::TWO_ADIC_ROOT_OF_UNITY.0;
+        let mut inverse = root;
+        let mut i = 1;
+        while i < MontField::<T, N>::TWO_ADICITY {
+            inverse = mont_mul::<T, N>(&mont_mul::<T, N>(&inverse, &inverse), &root);
+            i += 1;
         }
+        Fp::new_unchecked(inverse)
+    };
 
-        impl core::iter::Product for crate::ArkScalarWrapper<$field> {
-            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-                use ff::Field;
-                iter.fold(Self::ONE, |acc, x| acc * x)
-            }
+    /// `MULTIPLICATIVE_GENERATOR^(2^S)`.
+    const DELTA: MontField<T, N> = {
+        let mut delta = T::GENERATOR.0;
+        let mut i = 0;
+        while i < MontField::<T, N>::TWO_ADICITY {
+            delta = mont_mul::<T, N>(&delta, &delta);
+            i += 1;
         }
+        Fp::new_unchecked(delta)
+    };
 
-        impl<'a> core::iter::Product<&'a Self> for crate::ArkScalarWrapper<$field> {
-            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-                use ff::Field;
-                iter.fold(Self::ONE, |acc, x| acc * x)
+    /// The modulus as big-endian hex with a `0x` prefix, padded to the end of a fixed buffer.
+    const MODULUS_HEX: ([u8; MAX_MODULUS_HEX_LEN], usize) = {
+        assert!(N <= MAX_MODULUS_LIMBS, "modulus too long to render");
+        let mut hex = [0u8; MAX_MODULUS_HEX_LEN];
+        hex[0] = b'0';
+        hex[1] = b'x';
+        let mut len = 2;
+        let mut i = 16 * N;
+        while i > 0 {
+            i -= 1;
+            let nibble = (T::MODULUS.0[i / 16] >> (4 * (i % 16))) & 0xf;
+            // Leading zeros are skipped
+            if nibble != 0 || len > 2 {
+                hex[len] = b"0123456789abcdef"[nibble as usize];
+                len += 1;
             }
         }
+        (hex, len)
+    };
+    const MODULUS_HEX_BYTES: &'static [u8; MAX_MODULUS_HEX_LEN] = &Self::MODULUS_HEX.0;
+    const MODULUS: &'static str = {
+        let (hex, _) = Self::MODULUS_HEX_BYTES.split_at(Self::MODULUS_HEX.1);
+        match core::str::from_utf8(hex) {
+            Ok(hex) => hex,
+            Err(_) => panic!("modulus hex is ASCII"),
+        }
     };
 }
 
-#[macro_export]
-macro_rules! impl_prime_field {
-    ($field:ty, $constants:ident) => {
-        impl elliptic_curve::PrimeField for $crate::ArkScalarWrapper<$field> {
-            type Repr = $crate::scalar::ScalarRepr<$field>;
-
-            const MODULUS: &'static str = crate::$constants::MODULUS;
-            const NUM_BITS: u32 = crate::$constants::NUM_BITS;
-            const CAPACITY: u32 = crate::$constants::CAPACITY;
-
-            const TWO_INV: Self = unsafe {
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::TWO_INV;
-                std::mem::transmute(bytes)
-            };
-            const MULTIPLICATIVE_GENERATOR: Self = unsafe {
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::GENERATOR;
-                std::mem::transmute(bytes)
-            };
-            const S: u32 = crate::$constants::TWO_ADICITY;
-            const ROOT_OF_UNITY: Self = unsafe {
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::ROOT_OF_UNITY;
-                std::mem::transmute(bytes)
-            };
-            const ROOT_OF_UNITY_INV: Self = unsafe {
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::ROOT_OF_UNITY_INV;
-                std::mem::transmute(bytes)
-            };
-            const DELTA: Self = unsafe {
-                let bytes: [u8; std::mem::size_of::<Self>()] = crate::$constants::DELTA;
-                std::mem::transmute(bytes)
-            };
-
-            fn from_repr(repr: Self::Repr) -> subtle::CtOption<Self> {
-                use ark_ff::PrimeField;
-                let field_element = <$field>::from_be_bytes_mod_order(repr.as_ref());
-                subtle::CtOption::new(Self::new(field_element), subtle::Choice::from(1))
-            }
+impl<T: MontConfig<N>, const N: usize> ff::PrimeField for ArkScalarWrapper<MontField<T, N>> {
+    type Repr = ScalarRepr<MontField<T, N>>;
+
+    const MODULUS: &'static str = MontConstants::<T, N>::MODULUS;
+    const NUM_BITS: u32 = MontField::<T, N>::MODULUS_BIT_SIZE;
+    const CAPACITY: u32 = Self::NUM_BITS - 1;
+
+    const TWO_INV: Self = Self::new(MontConstants::<T, N>::TWO_INV);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::new(T::GENERATOR);
+    const S: u32 = MontField::<T, N>::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::new(T::TWO_ADIC_ROOT_OF_UNITY);
+    const ROOT_OF_UNITY_INV: Self = Self::new(MontConstants::<T, N>::ROOT_OF_UNITY_INV);
+    const DELTA: Self = Self::new(MontConstants::<T, N>::DELTA);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        // `to_repr` stores the canonical limbs, so read them back the same way and
+        // reject anything that is not reduced modulo the field characteristic.
+        match MontField::<T, N>::from_bigint(repr.into_bigint()) {
+            Some(field_element) => CtOption::new(Self::new(field_element), Choice::from(1)),
+            None => CtOption::new(Self::new(MontField::<T, N>::ZERO), Choice::from(0)),
+        }
+    }
 
-            fn to_repr(&self) -> Self::Repr {
-                use ark_ff::PrimeField;
-                crate::scalar::ScalarRepr::new(self.inner.into_bigint())
-            }
+    fn to_repr(&self) -> Self::Repr {
+        ScalarRepr::new(self.inner.into_bigint())
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_repr().as_ref()[0] & 1)
+    }
+}
 
-            fn is_odd(&self) -> subtle::Choice {
-                subtle::Choice::from(self.to_repr().as_ref()[0] & 1)
+impl<T: MontConfig<N>, const N: usize> ff::PrimeFieldBits for ArkScalarWrapper<MontField<T, N>> {
+    type ReprBits = [u64; N];
+
+    fn to_le_bits(&self) -> ff::FieldBits<Self::ReprBits> {
+        to_field_bits(&self.inner.into_bigint())
+    }
+
+    fn char_le_bits() -> ff::FieldBits<Self::ReprBits> {
+        to_field_bits(&T::MODULUS)
+    }
+}
+
+/// Implements `hash2curve::FromOkm` for an arkworks prime field, reading `$length` bytes of
+/// `expand_message` output per element. RFC 9380 `hash_to_field` reads
+/// `ceil((ceil(log2(p)) + 128) / 8)` bytes, which is checked at compile time.
+///
+/// This is the one per-field impl left: the length is a `typenum` type, which cannot be computed
+/// from the field's associated constants.
+macro_rules! impl_from_okm {
+    ($field:ty, $length:ty) => {
+        impl elliptic_curve::hash2curve::FromOkm for $crate::ArkScalarWrapper<$field> {
+            type Length = $length;
+
+            fn from_okm(
+                data: &elliptic_curve::generic_array::GenericArray<u8, Self::Length>,
+            ) -> Self {
+                use ark_ff::PrimeField;
+                use elliptic_curve::generic_array::typenum::Unsigned;
+                const {
+                    assert!(
+                        <$length>::USIZE == (<$field>::MODULUS_BIT_SIZE as usize + 128).div_ceil(8),
+                        "the okm length does not match RFC 9380 for this field"
+                    )
+                };
+                // RFC 9380 reads the output of `expand_message` as a big-endian integer
+                Self::new(<$field>::from_be_bytes_mod_order(data))
             }
         }
     };
 }
+
+pub(crate) use impl_from_okm;
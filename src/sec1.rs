@@ -0,0 +1,150 @@
+//! SEC1 point encodings for the short Weierstrass curves standardised by SECG.
+//!
+//! Arkworks serialises these curves as a little-endian x-coordinate with flag bits in the top
+//! byte, which other implementations cannot read. The impls here produce the `0x02`/`0x03`/`0x04`
+//! tagged, big-endian encodings from SEC1 instead, byte-for-byte identical to `k256`/`p256`/`p384`.
+
+use crate::ArkGroupWrapper;
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField, Zero};
+use elliptic_curve::sec1::{Coordinates, EncodedPoint, ModulusSize};
+use elliptic_curve::{Curve, FieldBytes, FieldBytesSize};
+use subtle::{Choice, CtOption};
+
+fn field_to_bytes<C, F>(element: &F) -> FieldBytes<C>
+where
+    C: Curve,
+    F: ArkPrimeField,
+{
+    let bytes = element.into_bigint().to_bytes_be();
+    let mut out = FieldBytes::<C>::default();
+    let offset = bytes.len() - out.len();
+    out.copy_from_slice(&bytes[offset..]);
+    out
+}
+
+fn field_from_bytes<C, F>(bytes: &FieldBytes<C>) -> Option<F>
+where
+    C: Curve,
+    F: ArkPrimeField,
+{
+    // Reject encodings that are not reduced modulo the base field characteristic
+    let element = F::from_be_bytes_mod_order(bytes);
+    (field_to_bytes::<C, F>(&element) == *bytes).then_some(element)
+}
+
+pub(crate) fn to_encoded_point<C, P>(point: &Projective<P>, compress: bool) -> EncodedPoint<C>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+    P: SWCurveConfig,
+    P::BaseField: ArkPrimeField,
+{
+    let affine = point.into_affine();
+    match affine.xy() {
+        Some((x, y)) => EncodedPoint::<C>::from_affine_coordinates(
+            &field_to_bytes::<C, _>(&x),
+            &field_to_bytes::<C, _>(&y),
+            compress,
+        ),
+        None => EncodedPoint::<C>::identity(),
+    }
+}
+
+pub(crate) fn from_encoded_point<C, P>(point: &EncodedPoint<C>) -> Option<Projective<P>>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+    P: SWCurveConfig,
+    P::BaseField: ArkPrimeField,
+{
+    match point.coordinates() {
+        Coordinates::Identity => Some(Affine::<P>::zero()),
+        Coordinates::Compressed { x, y_is_odd } => {
+            field_from_bytes::<C, P::BaseField>(x).and_then(|x| {
+                let (y, neg_y) = Affine::<P>::get_ys_from_x_unchecked(x)?;
+                let y = if y.into_bigint().is_odd() == y_is_odd {
+                    y
+                } else {
+                    neg_y
+                };
+                Some(Affine::<P>::new_unchecked(x, y))
+            })
+        }
+        Coordinates::Uncompressed { x, y } => field_from_bytes::<C, P::BaseField>(x)
+            .zip(field_from_bytes::<C, P::BaseField>(y))
+            .map(|(x, y)| Affine::<P>::new_unchecked(x, y))
+            .filter(Affine::is_on_curve),
+        // Compact points are not part of SEC1 and are not supported
+        Coordinates::Compact { .. } => None,
+    }
+    .filter(Affine::is_in_correct_subgroup_assuming_on_curve)
+    .map(Affine::into_group)
+}
+
+macro_rules! impl_sec1_curve {
+    ($(#[$meta:meta])* $name:ident, $config:ty, $field_bytes_size:ty, $uint:ty, $order:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name;
+
+        impl elliptic_curve::Curve for $name {
+            type FieldBytesSize = $field_bytes_size;
+            type Uint = $uint;
+
+            const ORDER: $uint = <$uint>::from_be_hex($order);
+        }
+
+        impl elliptic_curve::PrimeCurve for $name {}
+
+        impl elliptic_curve::FieldBytesEncoding<$name> for $uint {}
+
+        impl elliptic_curve::sec1::ToEncodedPoint<$name>
+            for ArkGroupWrapper<Projective<$config>>
+        {
+            fn to_encoded_point(&self, compress: bool) -> EncodedPoint<$name> {
+                to_encoded_point::<$name, $config>(&self.0, compress)
+            }
+        }
+
+        impl elliptic_curve::sec1::FromEncodedPoint<$name>
+            for ArkGroupWrapper<Projective<$config>>
+        {
+            fn from_encoded_point(point: &EncodedPoint<$name>) -> CtOption<Self> {
+                // Note: This is not constant-time, but arkworks doesn't provide constant-time point recovery
+                match from_encoded_point::<$name, $config>(point) {
+                    Some(point) => CtOption::new(Self::new(point), Choice::from(1)),
+                    None => CtOption::new(Self::new(Projective::<$config>::zero()), Choice::from(0)),
+                }
+            }
+        }
+    };
+}
+
+impl_sec1_curve!(
+    /// The secp256k1 curve, used to tag SEC1 encodings of `ArkGroupWrapper<ark_secp256k1::Projective>`.
+    Secp256k1,
+    ark_secp256k1::Config,
+    elliptic_curve::consts::U32,
+    elliptic_curve::bigint::U256,
+    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+);
+
+impl_sec1_curve!(
+    /// The NIST P-256 curve, used to tag SEC1 encodings of `ArkGroupWrapper<ark_secp256r1::Projective>`.
+    Secp256r1,
+    ark_secp256r1::Config,
+    elliptic_curve::consts::U32,
+    elliptic_curve::bigint::U256,
+    "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+);
+
+impl_sec1_curve!(
+    /// The NIST P-384 curve, used to tag SEC1 encodings of `ArkGroupWrapper<ark_secp384r1::Projective>`.
+    Secp384r1,
+    ark_secp384r1::Config,
+    elliptic_curve::consts::U48,
+    elliptic_curve::bigint::U384,
+    "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973"
+);
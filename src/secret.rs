@@ -0,0 +1,49 @@
+//! Secret scalars that are wiped from memory when dropped.
+
+use crate::ArkScalarWrapper;
+use ark_ff::PrimeField as ArkPrimeField;
+use core::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A scalar holding secret material, such as a private key or a DKG secret share.
+///
+/// The scalar is zeroized on drop and never printed by `Debug`. Use [`SecretScalar::expose_secret`]
+/// to borrow the wrapped value for arithmetic.
+#[derive(Clone)]
+pub struct SecretScalar<F: ArkPrimeField>(ArkScalarWrapper<F>);
+
+impl<F: ArkPrimeField> SecretScalar<F> {
+    pub fn new(scalar: ArkScalarWrapper<F>) -> Self {
+        Self(scalar)
+    }
+
+    pub fn expose_secret(&self) -> &ArkScalarWrapper<F> {
+        &self.0
+    }
+}
+
+impl<F: ArkPrimeField> From<ArkScalarWrapper<F>> for SecretScalar<F> {
+    fn from(scalar: ArkScalarWrapper<F>) -> Self {
+        Self::new(scalar)
+    }
+}
+
+impl<F: ArkPrimeField> fmt::Debug for SecretScalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretScalar(..)")
+    }
+}
+
+impl<F: ArkPrimeField> Zeroize for SecretScalar<F> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<F: ArkPrimeField> Drop for SecretScalar<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F: ArkPrimeField> ZeroizeOnDrop for SecretScalar<F> {}
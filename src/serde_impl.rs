@@ -0,0 +1,167 @@
+//! `serde` support for the wrappers and their byte representations.
+//!
+//! Values are written through their canonical `GroupEncoding`/`PrimeField` representations: as a
+//! lowercase hex string for human-readable formats, and as a fixed-length tuple of bytes for
+//! binary formats so that no length prefix goes on the wire. Deserialisation rejects anything
+//! that does not decode to a valid point or a reduced scalar.
+
+use crate::group::{PointRepr, UncompressedPointRepr};
+use crate::scalar::ScalarRepr;
+use crate::{ArkGroupWrapper, ArkScalarWrapper};
+use ark_ff::PrimeField as ArkPrimeField;
+use core::fmt;
+use elliptic_curve::group::GroupEncoding;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::encode(bytes))
+    } else {
+        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Fills `out` with exactly `out.len()` bytes read from `deserializer`.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    out: &mut [u8],
+    deserializer: D,
+) -> Result<(), D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HexVisitor(out))
+    } else {
+        let len = out.len();
+        deserializer.deserialize_tuple(len, ByteArrayVisitor(out))
+    }
+}
+
+struct HexVisitor<'a>(&'a mut [u8]);
+
+impl Visitor<'_> for HexVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a hex string of {} bytes", self.0.len())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<(), E> {
+        hex::decode_to_slice(value, self.0).map_err(E::custom)
+    }
+}
+
+struct ByteArrayVisitor<'a>(&'a mut [u8]);
+
+impl<'de> Visitor<'de> for ByteArrayVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an array of {} bytes", self.0.len())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let len = self.0.len();
+        for i in 0..len {
+            let byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            self.0[i] = byte;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(len + 1, &self));
+        }
+        Ok(())
+    }
+}
+
+impl<C> Serialize for ArkGroupWrapper<C>
+where
+    Self: GroupEncoding,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.to_bytes().as_ref(), serializer)
+    }
+}
+
+impl<'de, C> Deserialize<'de> for ArkGroupWrapper<C>
+where
+    Self: GroupEncoding,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut repr = <Self as GroupEncoding>::Repr::default();
+        deserialize_bytes(repr.as_mut(), deserializer)?;
+        Option::from(Self::from_bytes(&repr)).ok_or_else(|| de::Error::custom("invalid point"))
+    }
+}
+
+impl<F> Serialize for ArkScalarWrapper<F>
+where
+    F: ArkPrimeField,
+    Self: ff::PrimeField<Repr = ScalarRepr<F>>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use ff::PrimeField;
+        self.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for ArkScalarWrapper<F>
+where
+    F: ArkPrimeField,
+    Self: ff::PrimeField<Repr = ScalarRepr<F>>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use ff::PrimeField;
+        let repr = ScalarRepr::<F>::deserialize(deserializer)?;
+        Option::from(Self::from_repr(repr)).ok_or_else(|| de::Error::custom("invalid scalar"))
+    }
+}
+
+impl<const N: usize> Serialize for PointRepr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_ref(), serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for PointRepr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The curve is not known here, so the bytes are checked when they are decoded to a point
+        let mut repr = Self::default();
+        deserialize_bytes(repr.as_mut(), deserializer)?;
+        Ok(repr)
+    }
+}
+
+impl<const N: usize> Serialize for UncompressedPointRepr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_ref(), serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for UncompressedPointRepr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut repr = Self::default();
+        deserialize_bytes(repr.as_mut(), deserializer)?;
+        Ok(repr)
+    }
+}
+
+impl<F: ArkPrimeField> Serialize for ScalarRepr<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_ref(), serializer)
+    }
+}
+
+impl<'de, F: ArkPrimeField> Deserialize<'de> for ScalarRepr<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut repr = Self::default();
+        deserialize_bytes(repr.as_mut(), deserializer)?;
+        // Reject encodings that are not reduced modulo the field characteristic
+        F::from_bigint(repr.into_bigint())
+            .map(|_| repr)
+            .ok_or_else(|| de::Error::custom("non-canonical scalar"))
+    }
+}
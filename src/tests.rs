@@ -1,5 +1,36 @@
 use crate::{ArkGroupWrapper, ArkScalarWrapper};
 use elliptic_curve::Field;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts heap allocations made by the current thread so that tests can assert a code path
+/// is allocation free without being disturbed by other tests running in parallel.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    (result, after - before)
+}
 
 #[test]
 fn run_gennaro_dkg() {
@@ -165,3 +196,1945 @@ fn run_gennaro_dkg() {
     let computed_pk = ArkGroupWrapper::<ark_bls12_381::G1Projective>::generator() * sk;
     assert_eq!(computed_pk, pk1);
 }
+
+#[test]
+fn encoding_and_comparison_do_not_allocate() {
+    use elliptic_curve::group::{Group, GroupEncoding};
+    use subtle::ConstantTimeEq;
+
+    type G = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type F = ArkScalarWrapper<ark_bls12_381::Fr>;
+
+    let mut rng = rand_core::OsRng;
+    let point = G::random(&mut rng);
+    let a = F::random(&mut rng);
+    let b = F::random(&mut rng);
+
+    let (bytes, allocations) = count_allocations(|| point.to_bytes());
+    assert_eq!(allocations, 0);
+
+    let (decoded, allocations) = count_allocations(|| G::from_bytes(&bytes));
+    assert_eq!(allocations, 0);
+    assert_eq!(decoded.unwrap(), point);
+
+    let (equal, allocations) = count_allocations(|| (a.ct_eq(&a), a.ct_eq(&b)));
+    assert_eq!(allocations, 0);
+    assert_eq!(equal.0.unwrap_u8(), 1);
+    assert_eq!(equal.1.unwrap_u8(), 0);
+}
+
+#[test]
+fn from_bytes_checks_the_subgroup_without_allocating() {
+    use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_serialize::CanonicalSerialize;
+    use elliptic_curve::group::GroupEncoding;
+
+    fn check<P: SWCurveConfig + crate::ArkEncoding>() {
+        type G<P> = ArkGroupWrapper<Projective<P>>;
+
+        let point = G::<P>::new(ark_std::UniformRand::rand(&mut ark_std::test_rng()));
+        let bytes = point.to_bytes();
+        let (decoded, allocations) = count_allocations(|| G::<P>::from_bytes(&bytes));
+        assert_eq!(allocations, 0);
+        assert_eq!(decoded.unwrap(), point);
+
+        // The first x-coordinate on the curve gives a point outside the prime-order subgroup
+        let mut x = P::BaseField::from(1u64);
+        let outside = loop {
+            if let Some(candidate) = Affine::<P>::get_point_from_x_unchecked(x, false) {
+                break candidate;
+            }
+            x += P::BaseField::from(1u64);
+        };
+        assert!(!outside.is_in_correct_subgroup_assuming_on_curve());
+        let mut bytes = <G<P> as GroupEncoding>::Repr::default();
+        outside
+            .into_group()
+            .into_affine()
+            .serialize_compressed(bytes.as_mut())
+            .unwrap();
+        assert!(bool::from(G::<P>::from_bytes(&bytes).is_none()));
+    }
+
+    // BLS12-381 G1 checks the order itself, the others keep the arkworks check
+    check::<ark_bls12_381::g1::Config>();
+    check::<ark_bls12_381::g2::Config>();
+    check::<ark_bn254::g2::Config>();
+    check::<ark_bls12_377::g1::Config>();
+}
+
+#[test]
+fn field_constants_match_arkworks() {
+    use ark_ff::{FftField, Field as ArkField};
+    use elliptic_curve::PrimeField;
+
+    type F = ArkScalarWrapper<ark_bls12_381::Fr>;
+
+    assert_eq!(F::ONE.into_inner(), ark_bls12_381::Fr::ONE);
+    assert_eq!(
+        F::TWO_INV.into_inner(),
+        ark_bls12_381::Fr::from(2u64).inverse().unwrap()
+    );
+    assert_eq!(
+        F::MULTIPLICATIVE_GENERATOR.into_inner(),
+        ark_bls12_381::Fr::GENERATOR
+    );
+    assert_eq!(
+        F::ROOT_OF_UNITY.into_inner(),
+        ark_bls12_381::Fr::TWO_ADIC_ROOT_OF_UNITY
+    );
+    assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+}
+
+#[test]
+fn from_repr_reads_to_repr_and_rejects_unreduced() {
+    use crate::scalar::ScalarRepr;
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+
+    fn check<F: ArkPrimeField>()
+    where
+        ArkScalarWrapper<F>: PrimeField<Repr = ScalarRepr<F>>,
+    {
+        type W<F> = ArkScalarWrapper<F>;
+
+        let x = W::<F>::random(rand_core::OsRng);
+        assert_eq!(W::<F>::from_repr(x.to_repr()).unwrap(), x);
+
+        // The repr holds the canonical limbs, least significant byte first
+        let one = W::<F>::ONE.to_repr();
+        assert_eq!(one.as_ref()[0], 1);
+        assert!(one.as_ref()[1..].iter().all(|&byte| byte == 0));
+        assert_eq!(W::<F>::from_repr(one).unwrap(), W::<F>::ONE);
+
+        // The largest reduced value is accepted, and anything from the modulus up is rejected
+        // rather than reduced
+        let mut modulus_minus_one = F::MODULUS;
+        modulus_minus_one.sub_with_borrow(&F::BigInt::from(1u64));
+        assert_eq!(
+            W::<F>::from_repr(ScalarRepr::new(modulus_minus_one)).unwrap(),
+            -W::<F>::ONE
+        );
+        assert!(bool::from(
+            W::<F>::from_repr(ScalarRepr::new(F::MODULUS)).is_none()
+        ));
+        let mut all_ones = ScalarRepr::<F>::default();
+        all_ones.as_mut().fill(0xff);
+        assert!(bool::from(W::<F>::from_repr(all_ones).is_none()));
+    }
+
+    check::<ark_bls12_381::Fr>();
+    check::<ark_secp256k1::Fr>();
+    check::<ark_secp384r1::Fq>();
+}
+
+#[test]
+fn uncompressed_encoding_round_trip() {
+    use elliptic_curve::group::{Group, GroupEncoding, UncompressedEncoding};
+
+    type G = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+
+    let point = G::random(rand_core::OsRng);
+    let bytes = point.to_uncompressed();
+    assert_eq!(bytes.as_ref().len(), 2 * point.to_bytes().as_ref().len());
+    assert_eq!(G::from_uncompressed(&bytes).unwrap(), point);
+    assert_eq!(G::from_uncompressed_unchecked(&bytes).unwrap(), point);
+
+    let identity = G::identity().to_uncompressed();
+    assert_eq!(G::from_uncompressed(&identity).unwrap(), G::identity());
+
+    let mut corrupted = bytes;
+    corrupted.as_mut()[95] ^= 1;
+    assert!(bool::from(G::from_uncompressed(&corrupted).is_none()));
+    // The unchecked decoder may only skip the subgroup check, not the on-curve check
+    assert!(bool::from(
+        G::from_uncompressed_unchecked(&corrupted).is_none()
+    ));
+
+    fn check_off_curve<G: UncompressedEncoding + Group>() {
+        let mut corrupted = G::random(rand_core::OsRng).to_uncompressed();
+        corrupted.as_mut()[0] ^= 1;
+        assert!(bool::from(G::from_uncompressed(&corrupted).is_none()));
+        assert!(bool::from(
+            G::from_uncompressed_unchecked(&corrupted).is_none()
+        ));
+    }
+    check_off_curve::<ArkGroupWrapper<ark_secp256k1::Projective>>();
+    check_off_curve::<ArkGroupWrapper<ark_ed_on_bls12_381::EdwardsProjective>>();
+    check_off_curve::<ArkGroupWrapper<ark_ed25519::EdwardsProjective>>();
+}
+
+#[test]
+fn sec1_encoding_matches_k256() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::sec1::{EncodedPoint, FromEncodedPoint, ToEncodedPoint};
+    use elliptic_curve::{Group, PrimeField};
+
+    type G = ArkGroupWrapper<ark_secp256k1::Projective>;
+    type F = ArkScalarWrapper<ark_secp256k1::Fr>;
+
+    let secret = F::random(rand_core::OsRng);
+    let public = G::generator() * secret;
+
+    let mut secret_bytes = k256::FieldBytes::default();
+    secret_bytes.copy_from_slice(&secret.into_inner().into_bigint().to_bytes_be());
+    let k256_public = (k256::ProjectivePoint::GENERATOR
+        * k256::Scalar::from_repr(secret_bytes).unwrap())
+    .to_affine();
+
+    for compress in [true, false] {
+        let encoded = public.to_encoded_point(compress);
+        assert_eq!(
+            encoded.as_bytes(),
+            k256_public.to_encoded_point(compress).as_bytes()
+        );
+        assert_eq!(G::from_encoded_point(&encoded).unwrap(), public);
+    }
+
+    let identity = G::identity().to_encoded_point(true);
+    assert_eq!(identity.as_bytes(), &[0u8]);
+    assert_eq!(G::from_encoded_point(&identity).unwrap(), G::identity());
+
+    let mut not_on_curve = public.to_encoded_point(false).as_bytes().to_vec();
+    not_on_curve[64] ^= 1;
+    let not_on_curve = EncodedPoint::<crate::sec1::Secp256k1>::from_bytes(&not_on_curve).unwrap();
+    assert!(bool::from(G::from_encoded_point(&not_on_curve).is_none()));
+}
+
+#[test]
+fn sec1_encoding_nist_curves() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::bigint::Encoding;
+    use elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+    use elliptic_curve::{Curve, Group};
+
+    let p256 = ArkGroupWrapper::<ark_secp256r1::Projective>::generator();
+    assert_eq!(
+        p256.to_encoded_point(true).as_bytes()[..4],
+        [0x03, 0x6b, 0x17, 0xd1]
+    );
+    for compress in [true, false] {
+        let encoded = p256.to_encoded_point(compress);
+        assert_eq!(
+            ArkGroupWrapper::<ark_secp256r1::Projective>::from_encoded_point(&encoded).unwrap(),
+            p256
+        );
+    }
+
+    let p384 = ArkGroupWrapper::<ark_secp384r1::Projective>::random(rand_core::OsRng);
+    for compress in [true, false] {
+        let encoded = p384.to_encoded_point(compress);
+        assert_eq!(encoded.len(), if compress { 49 } else { 97 });
+        assert_eq!(
+            ArkGroupWrapper::<ark_secp384r1::Projective>::from_encoded_point(&encoded).unwrap(),
+            p384
+        );
+    }
+
+    assert_eq!(
+        crate::sec1::Secp256k1::ORDER.to_be_bytes().to_vec(),
+        ark_secp256k1::Fr::MODULUS.to_bytes_be()
+    );
+    assert_eq!(
+        crate::sec1::Secp256r1::ORDER.to_be_bytes().to_vec(),
+        ark_secp256r1::Fr::MODULUS.to_bytes_be()
+    );
+    assert_eq!(
+        crate::sec1::Secp384r1::ORDER.to_be_bytes().to_vec(),
+        ark_secp384r1::Fr::MODULUS.to_bytes_be()
+    );
+}
+
+/// Checks `0 * G, 1 * G, ..., 999 * G` against the Zcash-format vectors shipped with the
+/// zkcrypto `bls12_381` crate.
+fn check_zcash_test_vectors<G>(compressed: &[u8], uncompressed: &[u8])
+where
+    G: elliptic_curve::Group
+        + elliptic_curve::group::GroupEncoding
+        + elliptic_curve::group::UncompressedEncoding
+        + core::fmt::Debug,
+{
+    let compressed_len = G::Repr::default().as_ref().len();
+    let uncompressed_len = G::Uncompressed::default().as_ref().len();
+
+    let mut point = G::identity();
+    for (compressed, uncompressed) in compressed
+        .chunks(compressed_len)
+        .zip(uncompressed.chunks(uncompressed_len))
+    {
+        assert_eq!(point.to_bytes().as_ref(), compressed);
+        assert_eq!(point.to_uncompressed().as_ref(), uncompressed);
+
+        let mut repr = G::Repr::default();
+        repr.as_mut().copy_from_slice(compressed);
+        assert_eq!(G::from_bytes(&repr).unwrap(), point);
+
+        let mut repr = G::Uncompressed::default();
+        repr.as_mut().copy_from_slice(uncompressed);
+        assert_eq!(G::from_uncompressed(&repr).unwrap(), point);
+
+        point += G::generator();
+    }
+}
+
+#[test]
+fn bls12_381_g1_zcash_encoding() {
+    check_zcash_test_vectors::<ArkGroupWrapper<ark_bls12_381::G1Projective>>(
+        include_bytes!("test_vectors/bls12_381/g1_compressed_valid_test_vectors.dat"),
+        include_bytes!("test_vectors/bls12_381/g1_uncompressed_valid_test_vectors.dat"),
+    );
+}
+
+#[test]
+fn bls12_381_g2_zcash_encoding() {
+    check_zcash_test_vectors::<ArkGroupWrapper<ark_bls12_381::G2Projective>>(
+        include_bytes!("test_vectors/bls12_381/g2_compressed_valid_test_vectors.dat"),
+        include_bytes!("test_vectors/bls12_381/g2_uncompressed_valid_test_vectors.dat"),
+    );
+}
+
+#[test]
+fn bn254_evm_encoding() {
+    use elliptic_curve::Group;
+
+    type G1 = ArkGroupWrapper<ark_bn254::G1Projective>;
+    type G2 = ArkGroupWrapper<ark_bn254::G2Projective>;
+
+    // EIP-196: the G1 generator is (1, 2)
+    let mut expected = [0u8; 64];
+    expected[31] = 1;
+    expected[63] = 2;
+    assert_eq!(G1::generator().to_evm_bytes(), expected);
+    assert_eq!(G1::from_evm_bytes(&expected).unwrap(), G1::generator());
+
+    // EIP-197: Fq2 coordinates are written imaginary part first
+    let expected = hex::decode(concat!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    ))
+    .unwrap();
+    assert_eq!(G2::generator().to_evm_bytes().to_vec(), expected);
+
+    let point = G2::random(rand_core::OsRng);
+    assert_eq!(G2::from_evm_bytes(&point.to_evm_bytes()).unwrap(), point);
+
+    assert_eq!(G1::identity().to_evm_bytes(), [0u8; 64]);
+    assert_eq!(G2::from_evm_bytes(&[0u8; 128]).unwrap(), G2::identity());
+
+    let mut not_on_curve = G1::generator().to_evm_bytes();
+    not_on_curve[63] = 3;
+    assert!(bool::from(G1::from_evm_bytes(&not_on_curve).is_none()));
+}
+
+#[test]
+fn bls12_381_evm_encoding() {
+    use elliptic_curve::Group;
+
+    type G1 = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type G2 = ArkGroupWrapper<ark_bls12_381::G2Projective>;
+
+    // EIP-2537: base field elements are padded to 64 bytes
+    let padding = "00000000000000000000000000000000";
+    let expected = hex::decode(
+        [
+            padding,
+            "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+            padding,
+            "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+        ]
+        .concat(),
+    )
+    .unwrap();
+    assert_eq!(G1::generator().to_evm_bytes().to_vec(), expected);
+
+    // EIP-2537: Fq2 coordinates are written real part first
+    let expected = hex::decode(
+        [
+            padding,
+            "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+            padding,
+            "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+            padding,
+            "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+            padding,
+            "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+        ]
+        .concat(),
+    )
+    .unwrap();
+    assert_eq!(G2::generator().to_evm_bytes().to_vec(), expected);
+
+    let point = G1::random(rand_core::OsRng);
+    assert_eq!(G1::from_evm_bytes(&point.to_evm_bytes()).unwrap(), point);
+    let point = G2::random(rand_core::OsRng);
+    assert_eq!(G2::from_evm_bytes(&point.to_evm_bytes()).unwrap(), point);
+
+    assert_eq!(G2::identity().to_evm_bytes(), [0u8; 256]);
+    assert_eq!(G1::from_evm_bytes(&[0u8; 128]).unwrap(), G1::identity());
+
+    let mut bad_padding = G1::generator().to_evm_bytes();
+    bad_padding[0] = 1;
+    assert!(bool::from(G1::from_evm_bytes(&bad_padding).is_none()));
+}
+
+#[test]
+fn ed25519_rfc8032_encoding() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    type G = ArkGroupWrapper<ark_ed25519::EdwardsProjective>;
+    type F = ArkScalarWrapper<ark_ed25519::Fr>;
+
+    // RFC 8032, section 5.1: the base point has y = 4/5 and an even x
+    let mut expected = [0x66u8; 32];
+    expected[0] = 0x58;
+    assert_eq!(G::generator().to_bytes().as_ref(), expected);
+
+    let mut identity = [0u8; 32];
+    identity[0] = 1;
+    assert_eq!(G::identity().to_bytes().as_ref(), identity);
+
+    // Public keys from the RFC 8032 test vectors, section 7.1
+    for public_key in [
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+    ] {
+        let mut repr = <G as GroupEncoding>::Repr::default();
+        repr.as_mut()
+            .copy_from_slice(&hex::decode(public_key).unwrap());
+        assert_eq!(
+            G::from_bytes(&repr).unwrap().to_bytes().as_ref(),
+            repr.as_ref()
+        );
+    }
+
+    let point = G::random(rand_core::OsRng);
+    let bytes = point.to_bytes();
+    assert_eq!(G::from_bytes(&bytes).unwrap(), point);
+    assert_eq!((-point).to_bytes().as_ref()[31] ^ bytes.as_ref()[31], 0x80);
+
+    // y = p is not a canonical encoding
+    let mut non_canonical = <G as GroupEncoding>::Repr::default();
+    non_canonical.as_mut().copy_from_slice(
+        &hex::decode("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").unwrap(),
+    );
+    assert!(bool::from(G::from_bytes(&non_canonical).is_none()));
+
+    // (0, -1) has order two, so it is outside the prime-order subgroup
+    let mut torsion = <G as GroupEncoding>::Repr::default();
+    torsion.as_mut().copy_from_slice(
+        &hex::decode("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").unwrap(),
+    );
+    assert!(bool::from(G::from_bytes(&torsion).is_none()));
+
+    // Scalars are 32-byte little-endian, as in RFC 8032
+    let scalar = F::random(rand_core::OsRng);
+    assert_eq!(
+        scalar.to_repr().as_ref(),
+        scalar.into_inner().into_bigint().to_bytes_le()
+    );
+    assert_eq!(F::from_repr(scalar.to_repr()).unwrap(), scalar);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::group::{Group, GroupEncoding};
+    use serde::{Deserialize, Serialize};
+
+    type G = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type F = ArkScalarWrapper<ark_bls12_381::Fr>;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message {
+        commitment: G,
+        share: F,
+    }
+
+    let message = Message {
+        commitment: G::random(rand_core::OsRng),
+        share: F::random(rand_core::OsRng),
+    };
+
+    let json = serde_json::to_value(&message).unwrap();
+    assert_eq!(json["commitment"], message.commitment.to_string());
+    assert_eq!(json["share"], message.share.to_string());
+    assert_eq!(serde_json::from_value::<Message>(json).unwrap(), message);
+
+    // Binary formats get fixed-length arrays without a length prefix
+    let bare = serde_bare::to_vec(&message).unwrap();
+    assert_eq!(bare.len(), 48 + 32);
+    assert_eq!(serde_bare::from_slice::<Message>(&bare).unwrap(), message);
+
+    let mut not_on_curve = message.commitment.to_bytes();
+    not_on_curve.as_mut()[47] ^= 1;
+    let json = serde_json::to_string(&not_on_curve).unwrap();
+    assert!(serde_json::from_str::<G>(&json).is_err());
+
+    // The modulus itself is not a canonical scalar
+    let non_canonical = ark_bls12_381::Fr::MODULUS.to_bytes_le();
+    assert!(serde_bare::from_slice::<F>(&non_canonical).is_err());
+    assert!(serde_json::from_str::<F>("\"00\"").is_err());
+
+    // Real gennaro-dkg round 1 payloads survive both formats and are accepted in round 2
+    use gennaro_dkg::{Parameters, Round1BroadcastData, SecretParticipant};
+    use std::collections::BTreeMap;
+    use std::num::NonZeroUsize;
+
+    let parameters = Parameters::new(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3).unwrap());
+    let mut participants = (1..=3)
+        .map(|id| SecretParticipant::<G>::new(NonZeroUsize::new(id).unwrap(), parameters).unwrap())
+        .collect::<Vec<_>>();
+    let round1 = participants
+        .iter_mut()
+        .map(|participant| participant.round1().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut broadcasts = BTreeMap::new();
+    let mut p2p = BTreeMap::new();
+    for (id, (broadcast, peer_data)) in round1.iter().enumerate().skip(1) {
+        let json = serde_json::to_string(broadcast).unwrap();
+        let decoded: Round1BroadcastData<G> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+
+        let bare = serde_bare::to_vec(&decoded).unwrap();
+        let decoded: Round1BroadcastData<G> = serde_bare::from_slice(&bare).unwrap();
+        assert_eq!(serde_bare::to_vec(&decoded).unwrap(), bare);
+
+        broadcasts.insert(id + 1, decoded);
+        p2p.insert(id + 1, peer_data[&1].clone());
+    }
+    assert!(participants[0].round2(broadcasts, p2p).is_ok());
+}
+
+#[test]
+fn ark_serialize_delegates_to_inner() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    type G = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type F = ArkScalarWrapper<ark_bls12_381::Fr>;
+
+    #[derive(Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+    struct Proof {
+        commitments: Vec<G>,
+        response: F,
+    }
+
+    let proof = Proof {
+        commitments: vec![G::random(rand_core::OsRng), G::identity()],
+        response: F::random(rand_core::OsRng),
+    };
+
+    let mut compressed = Vec::new();
+    proof.serialize_compressed(&mut compressed).unwrap();
+    let mut inner = Vec::new();
+    proof.commitments[0]
+        .into_inner()
+        .serialize_compressed(&mut inner)
+        .unwrap();
+    assert_eq!(inner, proof.commitments[0].to_bytes().as_ref());
+    assert_eq!(compressed[8..8 + 48], inner);
+    assert_eq!(Proof::deserialize_compressed(&*compressed).unwrap(), proof);
+
+    let mut uncompressed = Vec::new();
+    proof.serialize_uncompressed(&mut uncompressed).unwrap();
+    assert_eq!(uncompressed.len(), proof.uncompressed_size());
+    assert_eq!(
+        Proof::deserialize_uncompressed(&*uncompressed).unwrap(),
+        proof
+    );
+
+    let mut not_on_curve = inner.clone();
+    not_on_curve[47] ^= 1;
+    assert!(G::deserialize_compressed(&*not_on_curve).is_err());
+    assert_eq!(
+        G::deserialize_with_mode(&*inner, Compress::Yes, Validate::No).unwrap(),
+        proof.commitments[0]
+    );
+}
+
+#[test]
+fn hex_formatting_matches_encoding() {
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    type G = ArkGroupWrapper<ark_secp256k1::Projective>;
+    type F = ArkScalarWrapper<ark_secp256k1::Fr>;
+
+    let point = G::random(rand_core::OsRng);
+    let scalar = F::random(rand_core::OsRng);
+
+    let encoded = hex::decode(point.to_string()).unwrap();
+    assert_eq!(encoded, point.to_bytes().as_ref());
+    assert_eq!(format!("{point:x}"), point.to_string());
+    assert_eq!(format!("{point:X}"), point.to_string().to_uppercase());
+    assert_eq!(format!("{:#x}", point.to_bytes()), format!("{point:#x}"));
+    assert_eq!(
+        format!("{:?}", point.to_bytes()),
+        format!("PointRepr(0x{point})")
+    );
+    assert_eq!(format!("{point:?}"), format!("ArkGroupWrapper(0x{point})"));
+    assert_eq!(
+        format!("{:?}", scalar.to_repr()),
+        format!("ScalarRepr(0x{scalar})")
+    );
+    assert_eq!(
+        hex::decode(scalar.to_string()).unwrap(),
+        scalar.to_repr().as_ref()
+    );
+
+    assert_eq!(point.to_string().parse::<G>().unwrap(), point);
+    assert_eq!(G::from_hex(&format!("{point:#X}")).unwrap(), point);
+    assert_eq!(scalar.to_string().parse::<F>().unwrap(), scalar);
+    assert_eq!(F::from_hex(&format!("{scalar:#x}")).unwrap(), scalar);
+
+    // Wrong lengths, invalid points and non-canonical scalars are rejected
+    assert!(G::from_hex(&point.to_string()[2..]).is_err());
+    let mut not_reduced = point.to_bytes();
+    not_reduced.as_mut()[..32].fill(0xff);
+    assert!(G::from_hex(&not_reduced.to_string()).is_err());
+    assert!("ff".repeat(32).parse::<F>().is_err());
+    assert!("zz".repeat(32).parse::<F>().is_err());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_secret_scalars() {
+    use crate::SecretScalar;
+    use elliptic_curve::PrimeField;
+    use zeroize::Zeroize;
+
+    type F = ArkScalarWrapper<ark_bls12_381::Fr>;
+
+    let mut share = F::random(rand_core::OsRng);
+    let mut repr = share.to_repr();
+    share.zeroize();
+    repr.zeroize();
+    assert_eq!(share, F::ZERO);
+    assert_eq!(repr.as_ref(), F::ZERO.to_repr().as_ref());
+
+    let share = F::random(rand_core::OsRng);
+    let mut secret = SecretScalar::from(share);
+    assert_eq!(*secret.expose_secret(), share);
+    assert_eq!(format!("{secret:?}"), "SecretScalar(..)");
+    assert!(!format!("{secret:?}").contains(&share.to_string()));
+    secret.zeroize();
+    assert_eq!(*secret.expose_secret(), F::ZERO);
+}
+
+/// Formats a field element the way the RFC 9380 test vectors do: each base prime field
+/// coefficient as `0x`-prefixed big-endian hex, separated by commas.
+fn rfc9380_coordinate<F: ark_ff::Field>(element: &F) -> String {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    element
+        .to_base_prime_field_elements()
+        .map(|coefficient| format!("0x{}", hex::encode(coefficient.into_bigint().to_bytes_be())))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Checks `hash` against a test vector file from RFC 9380, appendix J.
+fn check_rfc9380_vectors<P: ark_ec::short_weierstrass::SWCurveConfig>(
+    vectors: &str,
+    suite: &str,
+    hash: impl Fn(&[u8], &[u8]) -> ark_ec::short_weierstrass::Projective<P>,
+) {
+    use ark_ec::{AffineRepr, CurveGroup};
+
+    let vectors: serde_json::Value = serde_json::from_str(vectors).unwrap();
+    assert_eq!(vectors["ciphersuite"], suite);
+    let dst = vectors["dst"].as_str().unwrap().as_bytes();
+    for vector in vectors["vectors"].as_array().unwrap() {
+        let msg = vector["msg"].as_str().unwrap().as_bytes();
+        let (x, y) = hash(msg, dst).into_affine().xy().unwrap();
+        assert_eq!(vector["P"]["x"], rfc9380_coordinate(&x));
+        assert_eq!(vector["P"]["y"], rfc9380_coordinate(&y));
+    }
+}
+
+/// The messages used by every suite in RFC 9380, appendix J.
+fn rfc9380_messages() -> [String; 5] {
+    [
+        String::new(),
+        "abc".to_string(),
+        "abcdef0123456789".to_string(),
+        format!("q128_{}", "q".repeat(128)),
+        format!("a512_{}", "a".repeat(512)),
+    ]
+}
+
+/// Checks `hash` against the points RFC 9380, appendix J, lists for each of its messages.
+fn check_rfc9380_points<G: ark_ec::CurveGroup>(
+    suite: &str,
+    points: [(&str, &str); 5],
+    hash: impl Fn(&[u8], &[u8]) -> G,
+) {
+    use ark_ec::AffineRepr;
+
+    let dst = format!("QUUX-V01-CS02-with-{suite}");
+    for (msg, (x, y)) in rfc9380_messages().iter().zip(points) {
+        let (px, py) = hash(msg.as_bytes(), dst.as_bytes())
+            .into_affine()
+            .xy()
+            .unwrap();
+        assert_eq!(rfc9380_coordinate(&px), format!("0x{x}"));
+        assert_eq!(rfc9380_coordinate(&py), format!("0x{y}"));
+    }
+}
+
+#[test]
+fn bls12_381_hash_to_curve() {
+    use elliptic_curve::group::UncompressedEncoding;
+
+    type G1 = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type G2 = ArkGroupWrapper<ark_bls12_381::G2Projective>;
+
+    check_rfc9380_vectors(
+        include_str!("test_vectors/hash_to_curve/BLS12381G1_XMD-SHA-256_SSWU_RO_.json"),
+        G1::HASH_TO_CURVE_SUITE,
+        |msg, dst| G1::hash_to_curve(msg, dst).into_inner(),
+    );
+    check_rfc9380_vectors(
+        include_str!("test_vectors/hash_to_curve/BLS12381G2_XMD-SHA-256_SSWU_RO_.json"),
+        G2::HASH_TO_CURVE_SUITE,
+        |msg, dst| G2::hash_to_curve(msg, dst).into_inner(),
+    );
+
+    // RFC 9380, appendices J.9.2 and J.10.2, as Zcash-format uncompressed points
+    let g1_encode_to_curve = [
+        "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+        "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+        "0a7a047c4a8397b3446450642c2ac64d7239b61872c9ae7a59707a8f4f950f101e766afe58223b3bff3a19a7f754027c1383aebba1e4327ccff7cf9912bda0dbc77de048b71ef8c8a81111d71dc33c5e3aa6edee9cf6f5fe525d50cc50b77cc9",
+        "0e7a16a975904f131682edbb03d9560d3e48214c9986bd50417a77108d13dc957500edf96462a3d01e62dc6cd468ef110ae89e677711d05c30a48d6d75e76ca9fb70fe06c6dd6ff988683d89ccde29ac7d46c53bb97a59b1901abf1db66052db",
+    ];
+    let g2_encode_to_curve = [
+        "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb71498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+        "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+        "0da75be60fb6aa0e9e3143e40c42796edf15685cafe0279afd2a67c3dff1c82341f17effd402e4f1af240ea90f4b659b038af300ef34c7759a6caaa4e69363cafeed218a1f207e93b2c70d91a1263d375d6730bd6b6509dcac3ba5b567e85bf30492f4fed741b073e5a82580f7c663f9b79e036b70ab3e51162359cec4e77c78086fe879b65ca7a47d34374c8315ac5e19b148cbdf163cf0894f29660d2e7bfb2b68e37d54cc83fd4e6e62c020eaa48709302ef8e746736c0e19342cc1ce3df4",
+        "12c8c05c1d5fc7bfa847f4d7d81e294e66b9a78bc9953990c358945e1f042eedafce608b67fdd3ab0cb2e6e263b9b1ad0c5ae723be00e6c3f0efe184fdc0702b64588fe77dda152ab13099a3bacd3876767fa7bbad6d6fd90b3642e902b208f911c624c56dbe154d759d021eec60fab3d8b852395a89de497e48504366feedd4662d023af447d66926a28076813dd64604e77ddb3ede41b5ec4396b7421dd916efc68a358a0d7425bddd253547f2fb4830522358491827265dfc5bcc1928a569",
+        "1565c2f625032d232f13121d3cfb476f45275c303a037faa255f9da62000c2c864ea881e2bcddd111edc4a3c0da3e88d0ea4e7c33d43e17cc516a72f76437c4bf81d8f4eac69ac355d3bf9b71b8138d55dc10fd458be115afa798b55dac34be10f8991d2a1ad662e7b6f58ab787947f1fa607fce12dde171bc17903b012091b657e15333e11701edcf5b63ba2a561247043b6f5fe4e52c839148dc66f2b3751e69a0f6ebb3d056d6465d50d4108543ecd956e10fa1640dfd9bc0030cc2558d28",
+    ];
+    for ((msg, g1), g2) in rfc9380_messages()
+        .iter()
+        .zip(g1_encode_to_curve)
+        .zip(g2_encode_to_curve)
+    {
+        let dst = format!("QUUX-V01-CS02-with-{}", G1::ENCODE_TO_CURVE_SUITE);
+        let point = G1::encode_to_curve(msg.as_bytes(), dst.as_bytes());
+        assert_eq!(hex::encode(point.to_uncompressed()), g1);
+
+        let dst = format!("QUUX-V01-CS02-with-{}", G2::ENCODE_TO_CURVE_SUITE);
+        let point = G2::encode_to_curve(msg.as_bytes(), dst.as_bytes());
+        assert_eq!(hex::encode(point.to_uncompressed()), g2);
+    }
+}
+
+#[test]
+fn secp256k1_p256_edwards25519_hash_to_curve() {
+    type K256 = ArkGroupWrapper<ark_secp256k1::Projective>;
+    type P256 = ArkGroupWrapper<ark_secp256r1::Projective>;
+    type Ed25519 = ArkGroupWrapper<ark_ed25519::EdwardsProjective>;
+
+    // RFC 9380, appendix J.8.1
+    check_rfc9380_points(
+        K256::HASH_TO_CURVE_SUITE,
+        [
+            (
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ),
+            (
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ),
+            (
+                "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+            ),
+            (
+                "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+                "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+            ),
+            (
+                "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+                "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+            ),
+        ],
+        |msg, dst| K256::hash_to_curve(msg, dst).into_inner(),
+    );
+
+    // RFC 9380, appendix J.1.1
+    check_rfc9380_points(
+        P256::HASH_TO_CURVE_SUITE,
+        [
+            (
+                "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+            ),
+            (
+                "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+            ),
+            (
+                "65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+                "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+            ),
+            (
+                "4be61ee205094282ba8a2042bcb48d88dfbb609301c49aa8b078533dc65a0b5d",
+                "98f8df449a072c4721d241a3b1236d3caccba603f916ca680f4539d2bfb3c29e",
+            ),
+            (
+                "457ae2981f70ca85d8e24c308b14db22f3e3862c5ea0f652ca38b5e49cd64bc5",
+                "ecb9f0eadc9aeed232dabc53235368c1394c78de05dd96893eefa62b0f4757dc",
+            ),
+        ],
+        |msg, dst| P256::hash_to_curve(msg, dst).into_inner(),
+    );
+
+    // RFC 9380, appendix J.5.1
+    check_rfc9380_points(
+        Ed25519::HASH_TO_CURVE_SUITE,
+        [
+            (
+                "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            ),
+            (
+                "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            ),
+            (
+                "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+                "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+            ),
+            (
+                "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+                "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+            ),
+            (
+                "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+                "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+            ),
+        ],
+        |msg, dst| Ed25519::hash_to_curve(msg, dst).into_inner(),
+    );
+
+    // RFC 9380, appendix J.5.2
+    check_rfc9380_points(
+        Ed25519::ENCODE_TO_CURVE_SUITE,
+        [
+            (
+                "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+                "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+            ),
+            (
+                "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+                "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+            ),
+            (
+                "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+                "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+            ),
+            (
+                "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+                "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+            ),
+            (
+                "6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+                "2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+            ),
+        ],
+        |msg, dst| Ed25519::encode_to_curve(msg, dst).into_inner(),
+    );
+}
+
+#[test]
+fn secp256k1_p256_encode_to_curve() {
+    use elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+    use elliptic_curve::sec1::ToEncodedPoint;
+
+    type K256 = ArkGroupWrapper<ark_secp256k1::Projective>;
+    type P256 = ArkGroupWrapper<ark_secp256r1::Projective>;
+
+    // The `_NU_` suites have to match k256 and p256, which implement the same suites
+    for msg in rfc9380_messages() {
+        let msg = msg.as_bytes();
+
+        let dst = format!("QUUX-V01-CS02-with-{}", K256::ENCODE_TO_CURVE_SUITE);
+        let theirs = k256::Secp256k1::encode_from_bytes::<ExpandMsgXmd<sha2::Sha256>>(
+            &[msg],
+            &[dst.as_bytes()],
+        )
+        .unwrap();
+        assert_eq!(
+            K256::encode_to_curve(msg, dst.as_bytes())
+                .to_encoded_point(false)
+                .as_bytes(),
+            theirs.to_affine().to_encoded_point(false).as_bytes(),
+        );
+
+        let dst = format!("QUUX-V01-CS02-with-{}", P256::ENCODE_TO_CURVE_SUITE);
+        let theirs = p256::NistP256::encode_from_bytes::<ExpandMsgXmd<sha2::Sha256>>(
+            &[msg],
+            &[dst.as_bytes()],
+        )
+        .unwrap();
+        assert_eq!(
+            P256::encode_to_curve(msg, dst.as_bytes())
+                .to_encoded_point(false)
+                .as_bytes(),
+            theirs.to_affine().to_encoded_point(false).as_bytes(),
+        );
+    }
+}
+
+/// The `_RO_` encoding written against the RustCrypto traits only, as generic code would.
+fn rust_crypto_hash_to_curve<F, X>(msg: &[u8], dst: &[u8]) -> F::Output
+where
+    F: elliptic_curve::hash2curve::FromOkm
+        + elliptic_curve::hash2curve::MapToCurve
+        + Default
+        + Copy,
+    F::Output: elliptic_curve::group::cofactor::CofactorGroup,
+    X: for<'a> elliptic_curve::hash2curve::ExpandMsg<'a>,
+{
+    use elliptic_curve::group::cofactor::CofactorGroup;
+
+    let mut u = [F::default(); 2];
+    elliptic_curve::hash2curve::hash_to_field::<X, F>(&[msg], &[dst], &mut u).unwrap();
+    (u[0].map_to_curve() + u[1].map_to_curve())
+        .clear_cofactor()
+        .into()
+}
+
+#[test]
+fn map_to_curve_through_rust_crypto_traits() {
+    use crate::ArkExtFieldWrapper;
+    use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+    use elliptic_curve::group::cofactor::CofactorGroup;
+    use elliptic_curve::hash2curve::{ExpandMsgXmd, MapToCurve};
+    use sha2::{Sha256, Sha512};
+
+    type G1 = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    type G2 = ArkGroupWrapper<ark_bls12_381::G2Projective>;
+    type K256 = ArkGroupWrapper<ark_secp256k1::Projective>;
+    type P256 = ArkGroupWrapper<ark_secp256r1::Projective>;
+    type Ed25519 = ArkGroupWrapper<ark_ed25519::EdwardsProjective>;
+
+    for msg in rfc9380_messages() {
+        let msg = msg.as_bytes();
+        let dst = b"QUUX-V01-CS02-with-expander";
+        assert_eq!(
+            rust_crypto_hash_to_curve::<ArkScalarWrapper<ark_bls12_381::Fq>, ExpandMsgXmd<Sha256>>(
+                msg, dst
+            ),
+            G1::hash_to_curve(msg, dst),
+        );
+        assert_eq!(
+            rust_crypto_hash_to_curve::<ArkScalarWrapper<ark_secp256k1::Fq>, ExpandMsgXmd<Sha256>>(
+                msg, dst
+            ),
+            K256::hash_to_curve(msg, dst),
+        );
+        assert_eq!(
+            rust_crypto_hash_to_curve::<ArkScalarWrapper<ark_secp256r1::Fq>, ExpandMsgXmd<Sha256>>(
+                msg, dst
+            ),
+            P256::hash_to_curve(msg, dst),
+        );
+        assert_eq!(
+            rust_crypto_hash_to_curve::<ArkScalarWrapper<ark_ed25519::Fq>, ExpandMsgXmd<Sha512>>(
+                msg, dst
+            ),
+            Ed25519::hash_to_curve(msg, dst),
+        );
+
+        // Fq2 has no `FromOkm`, so hash to the field through arkworks
+        let hasher = <DefaultFieldHasher<Sha256, 128> as HashToField<ark_bls12_381::Fq2>>::new(dst);
+        let [u0, u1] = hasher.hash_to_field::<2>(msg);
+        let point =
+            ArkExtFieldWrapper::new(u0).map_to_curve() + ArkExtFieldWrapper::new(u1).map_to_curve();
+        assert_eq!(point.clear_cofactor(), G2::hash_to_curve(msg, dst));
+    }
+
+    // The map output of edwards25519 is not torsion free until the cofactor is cleared
+    let point = ArkScalarWrapper::new(ark_ed25519::Fq::from(3u8)).map_to_curve();
+    assert!(!bool::from(point.is_torsion_free()));
+    assert!(bool::from(point.clear_cofactor().is_torsion_free()));
+}
+
+#[test]
+fn hash_to_scalar_and_uniform_bytes() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+    use ff::FromUniformBytes;
+
+    type K256 = ArkScalarWrapper<ark_secp256k1::Fr>;
+
+    // The same suite through k256 has to produce the same scalar
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    for msg in rfc9380_messages() {
+        let ours = K256::hash_to_scalar(msg.as_bytes(), dst);
+        let theirs = k256::Secp256k1::hash_to_scalar::<ExpandMsgXmd<sha2::Sha256>>(
+            &[msg.as_bytes()],
+            &[dst],
+        )
+        .unwrap();
+        let mut bytes = ours.to_repr().as_ref().to_vec();
+        bytes.reverse();
+        assert_eq!(bytes, theirs.to_bytes().as_slice());
+    }
+
+    // Wide inputs are reduced modulo the group order
+    fn check_reduction<F: ArkPrimeField, const N: usize>()
+    where
+        ArkScalarWrapper<F>: FromUniformBytes<N>,
+    {
+        let mut bytes = [0u8; N];
+        let modulus = F::MODULUS.to_bytes_le();
+        bytes[..modulus.len()].copy_from_slice(&modulus);
+        bytes[0] = bytes[0].wrapping_add(5);
+        assert_eq!(
+            ArkScalarWrapper::<F>::from_uniform_bytes(&bytes),
+            ArkScalarWrapper::from(5)
+        );
+
+        // Compare with an independent reduction of the same little-endian integer
+        let modulus = num_bigint::BigUint::from_bytes_le(&F::MODULUS.to_bytes_le());
+        let mut inputs = vec![[0xff; N]];
+        for _ in 0..10 {
+            let mut input = [0u8; N];
+            rand_core::RngCore::fill_bytes(&mut rand_core::OsRng, &mut input);
+            inputs.push(input);
+        }
+        for input in inputs {
+            let expected = num_bigint::BigUint::from_bytes_le(&input) % &modulus;
+            let mut expected = expected.to_bytes_le();
+            expected.resize(F::MODULUS.to_bytes_le().len(), 0);
+            let reduced = ArkScalarWrapper::<F>::from_uniform_bytes(&input);
+            assert_eq!(reduced.to_repr().as_ref(), &expected[..]);
+        }
+    }
+    check_reduction::<ark_secp256k1::Fr, 48>();
+    check_reduction::<ark_secp256k1::Fr, 64>();
+    check_reduction::<ark_ed25519::Fr, 64>();
+    check_reduction::<ark_bls12_381::Fr, 64>();
+    check_reduction::<ark_secp384r1::Fr, 96>();
+    check_reduction::<ark_secp384r1::Fr, 128>();
+}
+
+#[test]
+fn reduce_matches_k256() {
+    use elliptic_curve::bigint::{Encoding, U256, U384, U512, U768};
+    use elliptic_curve::ops::{Reduce, ReduceNonZero};
+    use elliptic_curve::{Curve, PrimeField};
+
+    type K256 = ArkScalarWrapper<ark_secp256k1::Fr>;
+
+    fn to_k256(scalar: K256) -> k256::Scalar {
+        let mut bytes = scalar.to_repr().as_ref().to_vec();
+        bytes.reverse();
+        k256::Scalar::from_repr(*k256::FieldBytes::from_slice(&bytes)).unwrap()
+    }
+
+    let order = crate::sec1::Secp256k1::ORDER;
+    let narrow = [
+        U256::ZERO,
+        U256::ONE,
+        order.wrapping_sub(&U256::ONE),
+        order,
+        order.wrapping_add(&U256::ONE),
+        U256::MAX,
+        U256::from_be_hex("c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346"),
+    ];
+    for n in narrow {
+        assert_eq!(
+            to_k256(K256::reduce(n)),
+            <k256::Scalar as Reduce<U256>>::reduce(n)
+        );
+        assert_eq!(
+            to_k256(K256::reduce_nonzero(n)),
+            <k256::Scalar as ReduceNonZero<U256>>::reduce_nonzero(n)
+        );
+        assert_eq!(
+            to_k256(<K256 as Reduce<U256>>::reduce_bytes(&n.to_be_bytes())),
+            <k256::Scalar as Reduce<U256>>::reduce(n)
+        );
+
+        let wide = U512::from((n, U256::MAX.wrapping_sub(&n)));
+        assert_eq!(
+            to_k256(K256::reduce(wide)),
+            <k256::Scalar as Reduce<U512>>::reduce(wide)
+        );
+        assert_eq!(
+            to_k256(K256::reduce_nonzero(wide)),
+            <k256::Scalar as ReduceNonZero<U512>>::reduce_nonzero(wide)
+        );
+    }
+
+    // The conversion into Montgomery form is a constant-time multiplication by R^2, whose final
+    // subtraction is selected by a mask, so also compare random inputs
+    for _ in 0..100 {
+        let mut bytes = [0u8; 32];
+        rand_core::RngCore::fill_bytes(&mut rand_core::OsRng, &mut bytes);
+        let n = U256::from_be_bytes(bytes);
+        assert_eq!(
+            to_k256(K256::reduce(n)),
+            <k256::Scalar as Reduce<U256>>::reduce(n)
+        );
+    }
+
+    // Inputs narrower than the modulus are returned unchanged, and wider ones wrap around
+    type P384 = ArkScalarWrapper<ark_secp384r1::Fr>;
+    assert_eq!(
+        P384::reduce(U256::MAX) + P384::ONE,
+        P384::reduce_nonzero(U256::MAX)
+    );
+    assert_eq!(P384::reduce(U384::ZERO), P384::ZERO);
+    assert_eq!(P384::reduce_nonzero(U384::ZERO), P384::ONE);
+    let p384_order = crate::sec1::Secp384r1::ORDER;
+    assert_eq!(P384::reduce(p384_order.wrapping_add(&U384::ONE)), P384::ONE);
+    let wide = U768::from((p384_order, p384_order));
+    assert_eq!(P384::reduce(wide), P384::ZERO);
+}
+
+#[test]
+fn prime_field_bits() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::Group;
+    use ff::PrimeFieldBits;
+
+    fn check<F: ArkPrimeField>()
+    where
+        ArkScalarWrapper<F>: PrimeFieldBits,
+    {
+        let scalar = ArkScalarWrapper::<F>::random(&mut rand_core::OsRng);
+        let bits = scalar.to_le_bits();
+        assert_eq!(bits.len(), F::BigInt::NUM_LIMBS * 64);
+        assert!(
+            bits.iter()
+                .by_vals()
+                .eq(scalar.inner.into_bigint().to_bits_le())
+        );
+        assert!(
+            ArkScalarWrapper::<F>::char_le_bits()
+                .iter()
+                .by_vals()
+                .eq(F::MODULUS.to_bits_le())
+        );
+    }
+    check::<ark_bls12_381::Fr>();
+    check::<ark_secp256k1::Fr>();
+    check::<ark_secp384r1::Fr>();
+    check::<ark_ed25519::Fr>();
+
+    // A double-and-add ladder over the bits agrees with scalar multiplication
+    type G = ArkGroupWrapper<ark_secp256k1::Projective>;
+    let scalar = ArkScalarWrapper::<ark_secp256k1::Fr>::random(&mut rand_core::OsRng);
+    let ladder = scalar
+        .to_le_bits()
+        .iter()
+        .by_vals()
+        .rev()
+        .fold(G::identity(), |acc, bit| {
+            let acc = acc.double();
+            if bit { acc + G::generator() } else { acc }
+        });
+    assert_eq!(ladder, G::generator() * scalar);
+}
+
+#[test]
+fn pairing_engine_is_bilinear() {
+    use crate::pairing::ArkPairingEngine;
+    use elliptic_curve::group::{Curve, Group};
+    use pairing::{Engine, MillerLoopResult, MultiMillerLoop};
+
+    fn check<E: MultiMillerLoop>() {
+        let mut rng = rand_core::OsRng;
+        let a = E::Fr::random(&mut rng);
+        let b = E::Fr::random(&mut rng);
+        let p = (E::G1::generator() * a).to_affine();
+        let q = (E::G2::generator() * b).to_affine();
+
+        let expected = E::pairing(
+            &E::G1::generator().to_affine(),
+            &E::G2::generator().to_affine(),
+        ) * (a * b);
+        assert_eq!(E::pairing(&p, &q), expected);
+        assert!(!bool::from(expected.is_identity()));
+
+        // e(p, q) * e(-p, q) = 1, computed with a single final exponentiation
+        let q = E::G2Prepared::from(q);
+        let neg_p = -p;
+        let result = E::multi_miller_loop(&[(&p, &q), (&neg_p, &q)]).final_exponentiation();
+        assert!(bool::from(result.is_identity()));
+        let single = E::multi_miller_loop(&[(&p, &q)]);
+        assert_eq!(
+            (single + E::Result::default()).final_exponentiation(),
+            E::pairing(&p, &(E::G2::generator() * b).to_affine())
+        );
+    }
+    check::<ArkPairingEngine<ark_bls12_381::Bls12_381>>();
+    check::<ArkPairingEngine<ark_bls12_377::Bls12_377>>();
+    check::<ArkPairingEngine<ark_bn254::Bn254>>();
+    check::<ArkPairingEngine<ark_bw6_761::BW6_761>>();
+    check::<ArkPairingEngine<ark_mnt4_298::MNT4_298>>();
+    check::<ArkPairingEngine<ark_mnt6_298::MNT6_298>>();
+
+    // The engine agrees with the arkworks pairing it wraps
+    use ark_ec::{AffineRepr, pairing::Pairing};
+    type E = ArkPairingEngine<ark_bls12_381::Bls12_381>;
+    let p = ark_bls12_381::G1Affine::generator();
+    let q = ark_bls12_381::G2Affine::generator();
+    assert_eq!(
+        E::pairing(
+            &crate::ArkAffineWrapper::new(p),
+            &crate::ArkAffineWrapper::new(q)
+        )
+        .into_inner(),
+        ark_bls12_381::Bls12_381::pairing(p, q)
+    );
+}
+
+#[test]
+fn gt_group_encoding() {
+    use crate::pairing::ArkGtWrapper;
+    use ark_ec::pairing::Pairing;
+    use ark_serialize::CanonicalSerialize;
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    fn check<P: Pairing>()
+    where
+        ArkGtWrapper<P>: Group<Scalar = ArkScalarWrapper<P::ScalarField>> + GroupEncoding,
+        ArkScalarWrapper<P::ScalarField>: elliptic_curve::PrimeField,
+    {
+        let mut rng = rand_core::OsRng;
+        let scalar = ArkScalarWrapper::<P::ScalarField>::random(&mut rng);
+        let element = ArkGtWrapper::<P>::generator() * scalar;
+        let bytes = element.to_bytes();
+        assert_eq!(bytes.as_ref().len(), element.0.compressed_size());
+        assert_eq!(ArkGtWrapper::<P>::from_bytes(&bytes).unwrap(), element);
+        assert_eq!(
+            ArkGtWrapper::<P>::from_bytes(&ArkGtWrapper::<P>::identity().to_bytes()).unwrap(),
+            ArkGtWrapper::<P>::identity()
+        );
+
+        // A target field element outside the order-r subgroup only decodes unchecked
+        let mut outside = <ArkGtWrapper<P> as GroupEncoding>::Repr::default();
+        <P::TargetField as ark_ff::UniformRand>::rand(&mut rng)
+            .serialize_compressed(outside.as_mut())
+            .unwrap();
+        assert!(bool::from(
+            ArkGtWrapper::<P>::from_bytes(&outside).is_none()
+        ));
+        assert!(bool::from(
+            ArkGtWrapper::<P>::from_bytes_unchecked(&outside).is_some()
+        ));
+    }
+    check::<ark_bls12_381::Bls12_381>();
+    check::<ark_bls12_377::Bls12_377>();
+    check::<ark_bn254::Bn254>();
+    check::<ark_bw6_761::BW6_761>();
+    check::<ark_mnt4_298::MNT4_298>();
+    check::<ark_mnt6_298::MNT6_298>();
+}
+
+#[test]
+fn base_field_constants_match_arkworks() {
+    use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+
+    fn check<F: ArkPrimeField>()
+    where
+        ArkScalarWrapper<F>: PrimeField,
+    {
+        type W<F> = ArkScalarWrapper<F>;
+        assert_eq!(W::<F>::ONE.into_inner(), F::ONE);
+        assert_eq!(
+            W::<F>::TWO_INV.into_inner(),
+            F::from(2u64).inverse().unwrap()
+        );
+        assert_eq!(W::<F>::MULTIPLICATIVE_GENERATOR.into_inner(), F::GENERATOR);
+        assert_eq!(
+            W::<F>::ROOT_OF_UNITY.into_inner(),
+            F::TWO_ADIC_ROOT_OF_UNITY
+        );
+        assert_eq!(
+            W::<F>::ROOT_OF_UNITY * W::<F>::ROOT_OF_UNITY_INV,
+            W::<F>::ONE
+        );
+        assert_eq!(W::<F>::NUM_BITS, F::MODULUS_BIT_SIZE);
+
+        let x = W::<F>::random(rand_core::OsRng);
+        assert_eq!(W::<F>::from_repr(x.to_repr()).unwrap(), x);
+    }
+    check::<ark_secp256k1::Fq>();
+    check::<ark_secp256r1::Fq>();
+    check::<ark_secp384r1::Fq>();
+    check::<ark_ed25519::Fq>();
+    check::<ark_bls12_381::Fq>();
+    check::<ark_bn254::Fq>();
+    check::<ark_bw6_761::Fq>();
+    check::<ark_bls12_377::Fq>();
+    check::<ark_bw6_767::Fq>();
+    check::<ark_cp6_782::Fq>();
+    check::<ark_mnt4_298::Fq>();
+    check::<ark_mnt6_298::Fq>();
+    check::<ark_mnt4_753::Fq>();
+    check::<ark_mnt6_753::Fq>();
+    check::<ark_secq256k1::Fq>();
+    check::<ark_pallas::Fq>();
+    check::<ark_vesta::Fq>();
+    check::<ark_grumpkin::Fq>();
+    check::<ark_curve25519::Fq>();
+    check::<ark_ed_on_bls12_377::Fq>();
+    check::<ark_ed_on_bls12_381::Fq>();
+    check::<ark_ed_on_bls12_381_bandersnatch::Fq>();
+    check::<ark_ed_on_bn254::Fq>();
+    check::<ark_ed_on_cp6_782::Fq>();
+    check::<ark_ed_on_mnt4_298::Fq>();
+    check::<ark_ed_on_mnt4_753::Fq>();
+
+    // Coordinates read through the wrapper keep their value and parity
+    let point =
+        (ark_secp256k1::Projective::generator() * ark_secp256k1::Fr::from(7u64)).into_affine();
+    let x = ArkScalarWrapper::new(point.x().unwrap());
+    let y = ArkScalarWrapper::new(point.y().unwrap());
+    assert_eq!(x.to_repr().as_ref(), point.x.into_bigint().to_bytes_le());
+    assert_eq!(bool::from(y.is_odd()), point.y.into_bigint().is_odd());
+}
+
+#[test]
+fn extension_field_wrappers() {
+    use crate::ArkExtFieldWrapper;
+    use ark_ff::Field as ArkField;
+
+    fn check<F: ArkField>() {
+        type W<F> = ArkExtFieldWrapper<F>;
+        let mut rng = rand_core::OsRng;
+        let a = W::<F>::random(&mut rng);
+        let b = W::<F>::random(&mut rng);
+
+        assert_eq!(a * a.invert().unwrap(), W::<F>::ONE);
+        assert!(bool::from(W::<F>::ZERO.invert().is_none()));
+        assert_eq!((a + b).square(), a.square() + a * b.double() + b.square());
+        assert_eq!(a.pow_vartime([3]), a.cube());
+
+        // Squares have square roots, and `sqrt_ratio` follows the `ff` contract otherwise
+        let root = a.square().sqrt().unwrap();
+        assert!(root == a || root == -a);
+        let (is_square, ratio_root) = W::<F>::sqrt_ratio(&a.square(), &b.square());
+        assert!(bool::from(is_square));
+        assert_eq!(ratio_root.square() * b.square(), a.square());
+        let non_square = std::iter::repeat_with(|| W::<F>::new(F::rand(&mut rng)))
+            .find(|x| bool::from(x.sqrt().is_none()))
+            .unwrap();
+        let (is_square, root) = W::<F>::sqrt_ratio(&non_square, &W::<F>::ONE);
+        assert!(!bool::from(is_square));
+        assert!(bool::from(
+            (root.square() * non_square.invert().unwrap())
+                .sqrt()
+                .is_none()
+        ));
+        let (is_square, root) = W::<F>::sqrt_ratio(&W::<F>::ZERO, &b);
+        assert!(bool::from(is_square) && root == W::<F>::ZERO);
+        assert!(!bool::from(W::<F>::sqrt_ratio(&a, &W::<F>::ZERO).0));
+
+        // The Frobenius map has order equal to the extension degree
+        let degree = F::extension_degree() as usize;
+        assert_eq!(a.frobenius_map(degree), a);
+        assert_eq!(a.frobenius_map(1), a.pow_vartime(F::characteristic()));
+
+        let bytes = a.to_bytes();
+        assert_eq!(W::<F>::from_bytes(&bytes).unwrap(), a);
+        assert!(bool::from(W::<F>::from_bytes(&bytes[1..]).is_none()));
+    }
+    check::<ark_bls12_381::Fq2>();
+    check::<ark_bls12_381::Fq6>();
+    check::<ark_bls12_381::Fq12>();
+}
+
+#[test]
+fn affine_coordinates() {
+    use ark_ec::{AdditiveGroup, AffineRepr, CurveGroup, PrimeGroup};
+    use ark_ff::{BigInteger, Field as ArkField, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::bigint::U256;
+    use elliptic_curve::group::{Curve, Group};
+    use elliptic_curve::ops::Reduce;
+    use elliptic_curve::point::AffineCoordinates;
+
+    // Coordinates and the ECDSA `r = x(R) mod n` agree with k256
+    let scalar = ArkScalarWrapper::<ark_secp256k1::Fr>::random(rand_core::OsRng);
+    let point = (ArkGroupWrapper::<ark_secp256k1::Projective>::generator() * scalar).to_affine();
+    let k256_point = (k256::ProjectivePoint::GENERATOR
+        * <k256::Scalar as Reduce<U256>>::reduce(U256::from_le_slice(scalar.to_repr().as_ref())))
+    .to_affine();
+    assert_eq!(point.x().as_ref(), k256_point.x().as_slice());
+    assert_eq!(
+        bool::from(point.y_is_odd()),
+        bool::from(k256_point.y_is_odd())
+    );
+    // The ECDSA flow reduces the big-endian x-coordinate directly
+    let x: [u8; 32] = point.x().as_ref().try_into().unwrap();
+    let r = <ArkScalarWrapper<ark_secp256k1::Fr> as Reduce<U256>>::reduce_bytes(&x);
+    let k256_r = <k256::Scalar as Reduce<U256>>::reduce_bytes(&k256_point.x());
+    let mut r_bytes = r.to_repr().as_ref().to_vec();
+    r_bytes.reverse();
+    assert_eq!(r_bytes, k256_r.to_bytes().as_slice());
+
+    // Coordinates of fields whose size is not a whole number of limbs use the SEC1 field size
+    let point = ArkGroupWrapper::<ark_mnt4_298::G1Projective>::generator().to_affine();
+    assert_eq!(point.x().as_ref().len(), 38);
+    assert_eq!(
+        point.x().as_ref().to_vec(),
+        ark_mnt4_298::G1Projective::generator()
+            .into_affine()
+            .x
+            .into_bigint()
+            .to_bytes_be()[2..]
+            .to_vec()
+    );
+
+    // Ed25519 points carry the sign of x in their encoding
+    let point = (ArkGroupWrapper::<ark_ed25519::EdwardsProjective>::generator()
+        * ArkScalarWrapper::<ark_ed25519::Fr>::random(rand_core::OsRng))
+    .to_affine();
+    let encoding = elliptic_curve::group::GroupEncoding::to_bytes(&point);
+    assert_eq!(
+        bool::from(point.x_is_odd()),
+        encoding.as_ref()[31] >> 7 == 1
+    );
+    assert_eq!(point.y().as_ref(), {
+        let mut y = encoding.as_ref().to_vec();
+        y[31] &= 0x7f;
+        y.reverse();
+        y
+    });
+
+    // Points are rebuilt from their coordinates, and rejected off the curve or outside the
+    // prime-order subgroup
+    type G1 = ArkGroupWrapper<ark_bls12_381::G1Projective>;
+    let point = ark_bls12_381::G1Projective::generator()
+        .double()
+        .into_affine();
+    let (x, y) = point.xy().unwrap();
+    assert_eq!(G1::from_coordinates(x, y).unwrap().into_inner(), point);
+    assert!(bool::from(G1::from_coordinates(x, y.double()).is_none()));
+    let outside = (1u64..)
+        .find_map(|x| {
+            ark_bls12_381::G1Affine::get_point_from_x_unchecked(ark_bls12_381::Fq::from(x), true)
+        })
+        .unwrap();
+    assert!(!outside.is_in_correct_subgroup_assuming_on_curve());
+    assert!(bool::from(
+        G1::from_coordinates(outside.x, outside.y).is_none()
+    ));
+
+    type Ed = ArkGroupWrapper<ark_ed25519::EdwardsProjective>;
+    let point = ark_ed25519::EdwardsProjective::generator().into_affine();
+    assert_eq!(
+        Ed::from_coordinates(point.x, point.y).unwrap().into_inner(),
+        point
+    );
+    // (0, -1) has order two
+    let torsion = -ark_ed25519::Fq::ONE;
+    assert!(bool::from(
+        Ed::from_coordinates(ark_ed25519::Fq::ZERO, torsion).is_none()
+    ));
+}
+
+/// A curve config defined outside arkworks, reusing secp256k1's equation, generator and fields.
+mod custom_curve {
+    use ark_ec::CurveConfig;
+    use ark_ec::short_weierstrass::{self, SWCurveConfig};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Config;
+
+    impl CurveConfig for Config {
+        type BaseField = ark_secp256k1::Fq;
+        type ScalarField = ark_secp256k1::Fr;
+        const COFACTOR: &'static [u64] = ark_secp256k1::Config::COFACTOR;
+        const COFACTOR_INV: Self::ScalarField = ark_secp256k1::Config::COFACTOR_INV;
+    }
+
+    impl SWCurveConfig for Config {
+        const COEFF_A: Self::BaseField = ark_secp256k1::Config::COEFF_A;
+        const COEFF_B: Self::BaseField = ark_secp256k1::Config::COEFF_B;
+        const GENERATOR: short_weierstrass::Affine<Self> = short_weierstrass::Affine::new_unchecked(
+            ark_secp256k1::Config::GENERATOR.x,
+            ark_secp256k1::Config::GENERATOR.y,
+        );
+    }
+
+    pub type Projective = short_weierstrass::Projective<Config>;
+
+    // As written by `codegen::generate_curve_constants::<Projective>`
+    pub mod custom_curve_constants {
+        pub const COMPRESSED_POINT_SIZE: usize = 33;
+        pub const UNCOMPRESSED_POINT_SIZE: usize = 65;
+    }
+
+    crate::wrap_curve!(Config, custom_curve_constants);
+}
+
+/// Curve configs defined outside arkworks like `custom_curve`, one of which is never wrapped and one
+/// of which is wrapped with the constants of a different curve.
+mod unwrapped_curves {
+    use ark_ec::CurveConfig;
+    use ark_ec::short_weierstrass::{self, SWCurveConfig};
+
+    macro_rules! secp256k1_config {
+        ($config:ident) => {
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+            pub struct $config;
+
+            impl CurveConfig for $config {
+                type BaseField = ark_secp256k1::Fq;
+                type ScalarField = ark_secp256k1::Fr;
+                const COFACTOR: &'static [u64] = ark_secp256k1::Config::COFACTOR;
+                const COFACTOR_INV: Self::ScalarField = ark_secp256k1::Config::COFACTOR_INV;
+            }
+
+            impl SWCurveConfig for $config {
+                const COEFF_A: Self::BaseField = ark_secp256k1::Config::COEFF_A;
+                const COEFF_B: Self::BaseField = ark_secp256k1::Config::COEFF_B;
+                const GENERATOR: short_weierstrass::Affine<Self> =
+                    short_weierstrass::Affine::new_unchecked(
+                        ark_secp256k1::Config::GENERATOR.x,
+                        ark_secp256k1::Config::GENERATOR.y,
+                    );
+            }
+        };
+    }
+
+    // No `wrap_curve!`, so this config has no `ArkEncoding`
+    secp256k1_config!(Unencoded);
+
+    secp256k1_config!(Missized);
+    crate::wrap_curve!(Missized, bls12_381_g1_constants);
+
+    pub type UnencodedProjective = short_weierstrass::Projective<Unencoded>;
+    pub type MissizedProjective = short_weierstrass::Projective<Missized>;
+
+    // The BLS12-381 G1 sizes, which do not fit secp256k1 points
+    mod bls12_381_g1_constants {
+        pub const COMPRESSED_POINT_SIZE: usize = 48;
+        pub const UNCOMPRESSED_POINT_SIZE: usize = 96;
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the `ArkEncoding` repr length does not match the serialised point size")]
+fn missized_repr_is_caught() {
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    ArkGroupWrapper::<unwrapped_curves::MissizedProjective>::generator().to_bytes();
+}
+
+#[test]
+fn wrap_custom_curve() {
+    use custom_curve::Projective;
+    use elliptic_curve::group::prime::PrimeCurveAffine;
+    use elliptic_curve::group::{Curve, Group, GroupEncoding, UncompressedEncoding};
+
+    let mut generated = Vec::new();
+    crate::codegen::generate_curve_constants::<Projective>(
+        &mut generated,
+        "custom_curve_constants",
+    );
+    let generated = String::from_utf8(generated).unwrap();
+    assert!(generated.contains("pub const COMPRESSED_POINT_SIZE: usize = 33;"));
+    assert!(generated.contains("pub const UNCOMPRESSED_POINT_SIZE: usize = 65;"));
+
+    type G = ArkGroupWrapper<Projective>;
+    let scalar = ArkScalarWrapper::<ark_secp256k1::Fr>::random(rand_core::OsRng);
+    let point = G::generator() * scalar;
+    assert_eq!(G::from_bytes(&point.to_bytes()).unwrap(), point);
+    assert_eq!(
+        G::from_uncompressed(&point.to_uncompressed()).unwrap(),
+        point
+    );
+    assert_eq!(point.to_affine().to_curve(), point);
+
+    // The custom config computes the same points as the arkworks secp256k1 curve
+    let reference = ArkGroupWrapper::<ark_secp256k1::Projective>::generator() * scalar;
+    assert_eq!(point.to_bytes().as_ref(), reference.to_bytes().as_ref());
+}
+
+#[test]
+fn blanket_impls_need_no_setup() {
+    use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::group::{Group, GroupEncoding};
+
+    // Fields that no constants were generated for
+    fn check<F: ArkPrimeField>()
+    where
+        ArkScalarWrapper<F>: PrimeField,
+    {
+        type W<F> = ArkScalarWrapper<F>;
+        assert_eq!(
+            W::<F>::TWO_INV.into_inner(),
+            F::from(2u64).inverse().unwrap()
+        );
+        assert_eq!(
+            W::<F>::ROOT_OF_UNITY_INV.into_inner(),
+            F::TWO_ADIC_ROOT_OF_UNITY.inverse().unwrap()
+        );
+        assert_eq!(
+            W::<F>::DELTA.into_inner(),
+            F::GENERATOR.pow(F::from(2u64).pow([F::TWO_ADICITY as u64]).into_bigint())
+        );
+        assert_eq!(W::<F>::S, F::TWO_ADICITY);
+
+        let hex = hex::encode(F::MODULUS.to_bytes_be());
+        assert_eq!(
+            W::<F>::MODULUS,
+            format!("0x{}", hex.trim_start_matches('0'))
+        );
+    }
+    check::<ark_pallas::Fr>();
+    check::<ark_vesta::Fr>();
+    check::<ark_ed_on_bls12_381_bandersnatch::Fr>();
+    check::<ark_secp256r1::Fq>();
+    check::<ark_mnt4_753::Fr>();
+
+    // A curve without `ArkEncoding` still gets the group traits
+    type G = ArkGroupWrapper<unwrapped_curves::UnencodedProjective>;
+    let scalar = ArkScalarWrapper::<ark_secp256k1::Fr>::random(rand_core::OsRng);
+    assert_eq!(
+        G::generator() * scalar + G::generator(),
+        G::generator() * (scalar + ArkScalarWrapper::ONE)
+    );
+
+    // Newly wrapped curves round-trip through their encodings
+    type Pallas = ArkGroupWrapper<ark_pallas::Projective>;
+    let point = Pallas::random(rand_core::OsRng);
+    assert_eq!(Pallas::from_bytes(&point.to_bytes()).unwrap(), point);
+}
+
+#[test]
+fn any_curve_runtime_selection() {
+    use crate::any::{AnyCurve, AnyCurveError};
+    use crate::{AnyArkPoint, AnyArkScalar, ArkCurveId};
+    use elliptic_curve::group::Group;
+
+    for &curve in ArkCurveId::ALL {
+        assert_eq!(curve.name().parse::<ArkCurveId>(), Ok(curve));
+        assert_eq!(ArkCurveId::from_tag(curve.tag()), Some(curve));
+
+        let scalar = AnyArkScalar::random(curve, rand_core::OsRng);
+        let point = AnyArkPoint::generator(curve).checked_mul(&scalar).unwrap();
+        assert_eq!(point.curve(), curve);
+        assert_eq!(AnyArkPoint::from_bytes(&point.to_bytes()), Ok(point));
+        assert_eq!(AnyArkScalar::from_bytes(&scalar.to_bytes()), Ok(scalar));
+
+        // Generic code sees the same values through the bound group type
+        let doubled = crate::with_curve!(curve, |G| {
+            let scalar = scalar.downcast::<G>().unwrap();
+            AnyArkPoint::new(G::generator() * (scalar + scalar))
+        });
+        assert_eq!(Ok(doubled), point.checked_add(&point));
+    }
+
+    let g1 = AnyArkPoint::generator(ArkCurveId::Bls12_381G1);
+    let g2 = AnyArkPoint::generator(ArkCurveId::Bls12_381G2);
+    let mismatch = AnyCurveError::CurveMismatch {
+        expected: ArkCurveId::Bls12_381G1,
+        found: ArkCurveId::Bls12_381G2,
+    };
+    assert_eq!(g1.checked_add(&g2), Err(mismatch));
+    assert_eq!(
+        g1.checked_mul(&AnyArkScalar::one(ArkCurveId::Bls12_381G2)),
+        Err(mismatch)
+    );
+    assert_eq!(
+        AnyArkScalar::one(ArkCurveId::Bls12_381G1)
+            .checked_add(&AnyArkScalar::one(ArkCurveId::Bls12_381G2)),
+        Err(mismatch)
+    );
+    assert_eq!(
+        crate::any::curves::Bls12_381G1::try_from(g2),
+        Err(AnyCurveError::CurveMismatch {
+            expected: ArkCurveId::Bls12_381G1,
+            found: ArkCurveId::Bls12_381G2,
+        })
+    );
+    assert_eq!(
+        crate::any::curves::Bls12_381G1::from_any(g1),
+        Some(crate::any::curves::Bls12_381G1::generator())
+    );
+
+    // A G1 encoding under the G2 tag has the wrong length, and unknown tags are rejected
+    let mut bytes = g1.to_bytes();
+    bytes[0] = ArkCurveId::Bls12_381G2.tag();
+    assert_eq!(
+        AnyArkPoint::from_bytes(&bytes),
+        Err(AnyCurveError::InvalidEncoding)
+    );
+    bytes[0] = 0xff;
+    assert_eq!(
+        AnyArkPoint::from_bytes(&bytes),
+        Err(AnyCurveError::UnknownCurve)
+    );
+    assert_eq!(
+        "p256".parse::<ArkCurveId>(),
+        Err(AnyCurveError::UnknownCurve)
+    );
+}
+
+#[test]
+fn curve_info_registry() {
+    use crate::any::curves;
+    use crate::info::CurveFamily;
+    use crate::{ArkCurveId, all_curves};
+    use elliptic_curve::PrimeField;
+    use elliptic_curve::group::{Group, GroupEncoding, UncompressedEncoding};
+
+    assert_eq!(all_curves().count(), ArkCurveId::ALL.len());
+    for info in all_curves() {
+        assert_eq!(info.id.info(), info);
+        assert_eq!(info.name, info.id.to_string());
+        crate::with_curve!(info.id, |G| {
+            assert_eq!(G::info(), info);
+            assert_eq!(info.generator, G::generator().to_bytes().as_ref());
+            assert_eq!(info.compressed_size, info.generator.len());
+            assert_eq!(
+                info.uncompressed_size,
+                G::generator().to_uncompressed().as_ref().len()
+            );
+            assert_eq!(info.scalar_modulus, <G as Group>::Scalar::MODULUS);
+            assert_eq!(info.num_bits, <G as Group>::Scalar::NUM_BITS);
+        });
+    }
+
+    let g1 = curves::Bls12_381G1::info();
+    assert_eq!(g1.family, CurveFamily::ShortWeierstrass);
+    assert_eq!(g1.cofactor, &[0x8c00aaab0000aaab, 0x396c8c005555e156]);
+    assert_eq!(g1.base_field_degree, 1);
+    assert_eq!(g1.oid, None);
+    assert_eq!(
+        g1.hash_to_curve_suites,
+        &[
+            "BLS12381G1_XMD:SHA-256_SSWU_RO_",
+            "BLS12381G1_XMD:SHA-256_SSWU_NU_"
+        ]
+    );
+    let g2 = curves::Bls12_381G2::info();
+    assert_eq!(g2.base_field_degree, 2);
+    assert_eq!(g2.base_modulus, g1.base_modulus);
+    assert_eq!(
+        g1.base_modulus,
+        ArkScalarWrapper::<ark_bls12_381::Fq>::MODULUS
+    );
+
+    let ed25519 = ArkCurveId::Ed25519.info();
+    assert_eq!(ed25519.family, CurveFamily::TwistedEdwards);
+    assert_eq!(ed25519.cofactor, &[8]);
+    // 1.3.101.112 identifies the Ed25519 signature algorithm, not the curve
+    assert_eq!(ed25519.oid, None);
+    assert_eq!(
+        ed25519.hash_to_curve_suites,
+        &[
+            curves::Ed25519::HASH_TO_CURVE_SUITE,
+            curves::Ed25519::ENCODE_TO_CURVE_SUITE
+        ]
+    );
+    assert_eq!(
+        ed25519.base_modulus,
+        "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+    );
+    assert_eq!(ArkCurveId::Secp256k1.info().oid, Some("1.3.132.0.10"));
+}
+
+#[cfg(feature = "rust-crypto")]
+#[test]
+fn rust_crypto_scalar_is_an_ark_prime_field() {
+    use crate::RustCryptoScalar;
+    use ark_ff::{
+        AdditiveGroup, BigInteger, Field as ArkField, LegendreSymbol, PrimeField as ArkPrimeField,
+    };
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    type Scalar = RustCryptoScalar<k256::Scalar>;
+    type ArkFr = ark_secp256k1::Fr;
+
+    assert_eq!(Scalar::MODULUS, ArkFr::MODULUS);
+    let mut modulus_minus_one = Scalar::MODULUS;
+    modulus_minus_one.sub_with_borrow(&ark_ff::BigInt::from(1u64));
+    assert_eq!((-Scalar::ONE).into_bigint(), modulus_minus_one);
+    assert_eq!(
+        Scalar::MODULUS_MINUS_ONE_DIV_TWO,
+        ArkFr::MODULUS_MINUS_ONE_DIV_TWO
+    );
+    assert_eq!(Scalar::TRACE, ArkFr::TRACE);
+    assert_eq!(
+        Scalar::TRACE_MINUS_ONE_DIV_TWO,
+        ArkFr::TRACE_MINUS_ONE_DIV_TWO
+    );
+    assert_eq!(Scalar::MODULUS_BIT_SIZE, ArkFr::MODULUS_BIT_SIZE);
+    assert_eq!(
+        <Scalar as ark_ff::FftField>::TWO_ADICITY,
+        <ArkFr as ark_ff::FftField>::TWO_ADICITY
+    );
+
+    let to_ark = |x: Scalar| ArkFr::from_bigint(x.into_bigint()).unwrap();
+    let mut rng = ark_std::test_rng();
+    for _ in 0..10 {
+        let a: Scalar = ark_std::UniformRand::rand(&mut rng);
+        let b: Scalar = ark_std::UniformRand::rand(&mut rng);
+        assert_eq!(Scalar::from_bigint(a.into_bigint()), Some(a));
+        assert_eq!(to_ark(a + b), to_ark(a) + to_ark(b));
+        assert_eq!(to_ark(a - b), to_ark(a) - to_ark(b));
+        assert_eq!(to_ark(a * b), to_ark(a) * to_ark(b));
+        assert_eq!(to_ark(a / b), to_ark(a) / to_ark(b));
+        assert_eq!(to_ark(-a), -to_ark(a));
+        assert_eq!(a.legendre(), to_ark(a).legendre());
+        assert_eq!(a.cmp(&b), to_ark(a).cmp(&to_ark(b)));
+        assert_eq!(a.to_string(), to_ark(a).to_string());
+        assert_eq!(a.to_string().parse::<Scalar>(), Ok(a));
+
+        let square = a.square();
+        assert_eq!(square.legendre(), LegendreSymbol::QuadraticResidue);
+        assert_eq!(square.sqrt().unwrap().square(), square);
+
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        let mut ark_bytes = Vec::new();
+        to_ark(a).serialize_compressed(&mut ark_bytes).unwrap();
+        assert_eq!(bytes, ark_bytes);
+        assert_eq!(Scalar::deserialize_compressed(&bytes[..]).unwrap(), a);
+
+        let wide: Vec<u8> = (0..64)
+            .map(|_| ark_std::UniformRand::rand(&mut rng))
+            .collect();
+        assert_eq!(
+            to_ark(Scalar::from_le_bytes_mod_order(&wide)),
+            ArkFr::from_le_bytes_mod_order(&wide)
+        );
+    }
+    assert_eq!(Scalar::from(-5i8), -Scalar::from(5u8));
+    assert_eq!(to_ark(Scalar::from(u128::MAX)), ArkFr::from(u128::MAX));
+    assert_eq!("-1".parse::<Scalar>(), Ok(-Scalar::ONE));
+    assert_eq!(Scalar::ZERO.inverse(), None);
+
+    let modulus_bytes = Scalar::MODULUS.to_bytes_le();
+    assert!(Scalar::deserialize_compressed(&modulus_bytes[..]).is_err());
+}
+
+#[cfg(feature = "k256")]
+#[test]
+fn rust_crypto_point_is_an_ark_curve_group() {
+    use crate::{RustCryptoAffine, RustCryptoPoint, RustCryptoScalar};
+    use ark_ec::{AffineRepr, CurveGroup, PrimeGroup, VariableBaseMSM};
+    use ark_ff::{AdditiveGroup, Field as ArkField, PrimeField as ArkPrimeField, Zero};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, CanonicalSerializeWithFlags};
+
+    type Point = RustCryptoPoint<k256::ProjectivePoint>;
+    type Affine = RustCryptoAffine<k256::ProjectivePoint>;
+    type Scalar = RustCryptoScalar<k256::Scalar>;
+    type Base = RustCryptoScalar<k256::FieldElement>;
+
+    assert_eq!(Base::MODULUS, ark_secp256k1::Fq::MODULUS);
+    assert_eq!(Base::TRACE, ark_secp256k1::Fq::TRACE);
+    let mut modulus_minus_one = Base::MODULUS;
+    ark_ff::BigInteger::sub_with_borrow(&mut modulus_minus_one, &ark_ff::BigInt::from(1u64));
+    assert_eq!((-Base::ONE).into_bigint(), modulus_minus_one);
+
+    // A lazily reduced k256 field element compares equal to its canonical form
+    let seven = Base::from(7u64);
+    let mut sum = Base::ZERO;
+    for _ in 0..7 {
+        sum += Base::ONE;
+    }
+    assert_eq!(sum, seven);
+    assert_eq!(-(-seven), seven);
+    assert_eq!(seven.double() - seven, seven);
+
+    let to_ark_scalar = |x: Scalar| ark_secp256k1::Fr::from_bigint(x.into_bigint()).unwrap();
+    let to_ark = |point: Point| {
+        let mut bytes = Vec::new();
+        point.serialize_uncompressed(&mut bytes).unwrap();
+        ark_secp256k1::Projective::deserialize_uncompressed(&bytes[..]).unwrap()
+    };
+
+    let generator = Point::generator();
+    assert_eq!(to_ark(generator), ark_secp256k1::Projective::generator());
+    assert_eq!(to_ark(Point::zero()), ark_secp256k1::Projective::zero());
+
+    let mut rng = ark_std::test_rng();
+    let scalars: Vec<Scalar> = (0..8)
+        .map(|_| ark_std::UniformRand::rand(&mut rng))
+        .collect();
+    let points: Vec<Point> = scalars.iter().map(|s| generator * s).collect();
+    for (point, scalar) in points.iter().zip(&scalars) {
+        assert_eq!(
+            to_ark(*point),
+            ark_secp256k1::Projective::generator() * to_ark_scalar(*scalar)
+        );
+        assert_eq!(generator.mul_bigint(scalar.into_bigint()), *point);
+
+        for compress in [ark_serialize::Compress::Yes, ark_serialize::Compress::No] {
+            let mut bytes = Vec::new();
+            point.serialize_with_mode(&mut bytes, compress).unwrap();
+            let mut ark_bytes = Vec::new();
+            to_ark(*point)
+                .serialize_with_mode(&mut ark_bytes, compress)
+                .unwrap();
+            assert_eq!(bytes, ark_bytes);
+            assert_eq!(
+                Point::deserialize_with_mode(&bytes[..], compress, ark_serialize::Validate::Yes)
+                    .unwrap(),
+                *point
+            );
+        }
+        let affine = point.into_affine();
+        let (x, y) = affine.xy().unwrap();
+        assert_eq!(y.square(), x.square() * x + Base::from(7u64));
+    }
+
+    let bases = Point::normalize_batch(&points);
+    assert_eq!(bases.len(), points.len());
+    let expected: k256::ProjectivePoint = points
+        .iter()
+        .zip(&scalars)
+        .map(|(point, scalar)| point.inner * scalar.inner)
+        .sum();
+    assert_eq!(Point::msm(&bases, &scalars).unwrap().inner, expected);
+
+    let mut bytes = Vec::new();
+    Affine::generator()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert_eq!(Affine::from_random_bytes(&bytes), Some(Affine::generator()));
+
+    let mut bytes = Vec::new();
+    Point::zero().serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(
+        Point::deserialize_compressed(&bytes[..]).unwrap(),
+        Point::zero()
+    );
+    // An x coordinate with no point on the curve is rejected
+    let x = (0u64..)
+        .map(Base::from)
+        .find(|x| (x.square() * x + Base::from(7u64)).legendre().is_qnr())
+        .unwrap();
+    let mut bytes = Vec::new();
+    x.serialize_with_flags(&mut bytes, ark_ec::short_weierstrass::SWFlags::YIsPositive)
+        .unwrap();
+    assert_eq!(Affine::from_random_bytes(&bytes), None);
+    assert!(Point::deserialize_compressed(&bytes[..]).is_err());
+}